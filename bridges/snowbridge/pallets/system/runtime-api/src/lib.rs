@@ -2,12 +2,19 @@
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use snowbridge_core::AgentId;
+use codec::Codec;
+use snowbridge_core::{outbound::Command, AgentId, ChannelId};
 use xcm::VersionedLocation;
 
 sp_api::decl_runtime_apis! {
-	pub trait ControlApi
+	#[api_version(2)]
+	pub trait ControlApi<Balance> where Balance: Codec
 	{
 		fn agent_id(location: VersionedLocation) -> Option<AgentId>;
+
+		/// Quote the `(local, total)` fee for sending `command` on the channel `channel_id`, or
+		/// `None` if it cannot be sent.
+		#[api_version(2)]
+		fn quote_command_fee(channel_id: ChannelId, command: Command) -> Option<(Balance, Balance)>;
 	}
 }
@@ -2,10 +2,10 @@
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 //! Helpers for implementing runtime api
 
-use snowbridge_core::AgentId;
+use snowbridge_core::{outbound::Command, AgentId, ChannelId};
 use xcm::{prelude::*, VersionedLocation};
 
-use crate::{agent_id_of, Config};
+use crate::{agent_id_of, BalanceOf, Config, Pallet};
 
 pub fn agent_id<Runtime>(location: VersionedLocation) -> Option<AgentId>
 where
@@ -14,3 +14,13 @@ where
 	let location: Location = location.try_into().ok()?;
 	agent_id_of::<Runtime>(&location).ok()
 }
+
+pub fn quote_command_fee<Runtime>(
+	channel_id: ChannelId,
+	command: Command,
+) -> Option<(BalanceOf<Runtime>, BalanceOf<Runtime>)>
+where
+	Runtime: Config,
+{
+	Pallet::<Runtime>::quote_command_fee(channel_id, command).ok()
+}
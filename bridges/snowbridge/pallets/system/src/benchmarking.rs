@@ -159,6 +159,16 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_fee_account() -> Result<(), BenchmarkError> {
+		let account: T::AccountId = account("fee_account", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, account);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		SnowbridgeControl,
 		crate::mock::new_test_ext(true),
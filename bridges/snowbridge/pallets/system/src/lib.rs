@@ -69,7 +69,7 @@ use snowbridge_core::{
 };
 use sp_core::{RuntimeDebug, H160, H256};
 use sp_io::hashing::blake2_256;
-use sp_runtime::{traits::BadOrigin, DispatchError, SaturatedConversion};
+use sp_runtime::{traits::BadOrigin, DispatchError, FixedU128, SaturatedConversion};
 use sp_std::prelude::*;
 use xcm::prelude::*;
 use xcm_executor::traits::ConvertLocation;
@@ -151,7 +151,7 @@ pub mod pallet {
 		/// Token reserved for control operations
 		type Token: Mutate<Self::AccountId>;
 
-		/// TreasuryAccount to collect fees
+		/// TreasuryAccount to collect fees, unless overridden with [`Pallet::set_fee_account`]
 		#[pallet::constant]
 		type TreasuryAccount: Get<Self::AccountId>;
 
@@ -162,6 +162,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type InboundDeliveryCost: Get<BalanceOf<Self>>;
 
+		/// Lowest ETH/DOT exchange rate accepted as a pricing parameter
+		#[pallet::constant]
+		type MinExchangeRate: Get<FixedU128>;
+
+		/// Highest ETH/DOT exchange rate accepted as a pricing parameter
+		#[pallet::constant]
+		type MaxExchangeRate: Get<FixedU128>;
+
 		type WeightInfo: WeightInfo;
 
 		#[cfg(feature = "runtime-benchmarks")]
@@ -211,6 +219,10 @@ pub mod pallet {
 		PricingParametersChanged {
 			params: PricingParametersOf<T>,
 		},
+		/// The account that collects fees was changed
+		FeeAccountChanged {
+			account: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -243,6 +255,10 @@ pub mod pallet {
 	pub type PricingParameters<T: Config> =
 		StorageValue<_, PricingParametersOf<T>, ValueQuery, T::DefaultPricingParameters>;
 
+	/// The account that collects fees instead of `TreasuryAccount`, if set
+	#[pallet::storage]
+	pub type FeeAccountOverride<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -322,6 +338,8 @@ pub mod pallet {
 		/// Fee required: No
 		///
 		/// - `origin`: Must be root
+		/// - `params`: The pricing parameters. The exchange rate must lie within
+		///   `MinExchangeRate..=MaxExchangeRate`
 		#[pallet::call_index(2)]
 		#[pallet::weight((T::WeightInfo::set_pricing_parameters(), DispatchClass::Operational))]
 		pub fn set_pricing_parameters(
@@ -330,6 +348,11 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure_root(origin)?;
 			params.validate().map_err(|_| Error::<T>::InvalidPricingParameters)?;
+			ensure!(
+				(T::MinExchangeRate::get()..=T::MaxExchangeRate::get())
+					.contains(&params.exchange_rate),
+				Error::<T>::InvalidPricingParameters
+			);
 			PricingParameters::<T>::put(params.clone());
 
 			let command = Command::SetPricingParameters {
@@ -574,6 +597,26 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Sets the account that collects the fees charged for commands, in place of
+		/// `TreasuryAccount`.
+		///
+		/// Privileged. Can only be called by root.
+		///
+		/// Fee required: No
+		///
+		/// - `origin`: Must be root
+		/// - `account`: The account to collect fees
+		#[pallet::call_index(10)]
+		#[pallet::weight((T::WeightInfo::set_fee_account(), DispatchClass::Operational))]
+		pub fn set_fee_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			ensure_root(origin)?;
+
+			FeeAccountOverride::<T>::put(account.clone());
+
+			Self::deposit_event(Event::<T>::FeeAccountChanged { account });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -590,18 +633,33 @@ pub mod pallet {
 			};
 
 			if let Some((payer, fee)) = payment {
-				T::Token::transfer(
-					&payer,
-					&T::TreasuryAccount::get(),
-					fee,
-					Preservation::Preserve,
-				)?;
+				T::Token::transfer(&payer, &Self::fee_account(), fee, Preservation::Preserve)?;
 			}
 
 			T::OutboundQueue::deliver(ticket).map_err(|err| Error::<T>::Send(err))?;
 			Ok(())
 		}
 
+		/// The account that collects the fees charged for commands
+		pub fn fee_account() -> T::AccountId {
+			FeeAccountOverride::<T>::get().unwrap_or_else(T::TreasuryAccount::get)
+		}
+
+		/// Quote the fee for sending `command` on the Channel identified by `channel_id`,
+		/// without sending it.
+		///
+		/// Returns the `(local, total)` fee, which is what `send` charges for
+		/// `PaysFee::Partial` and `PaysFee::Yes` respectively.
+		pub fn quote_command_fee(
+			channel_id: ChannelId,
+			command: Command,
+		) -> Result<(BalanceOf<T>, BalanceOf<T>), Error<T>> {
+			let message = Message { id: None, channel_id, command };
+			let (_, fee) =
+				T::OutboundQueue::validate(&message).map_err(|err| Error::<T>::Send(err))?;
+			Ok((fee.local, fee.total()))
+		}
+
 		/// Issue a `Command::TransferNativeFromAgent` command. The command will be sent on the
 		/// channel `channel_id`
 		pub fn do_transfer_native_from_agent(
@@ -663,6 +721,11 @@ pub mod pallet {
 			let secondary_exists = Channels::<T>::contains_key(SECONDARY_GOVERNANCE_CHANNEL);
 			primary_exists && secondary_exists
 		}
+
+		/// Whether an agent with the given `id` is registered
+		pub fn agent_exists(id: AgentId) -> bool {
+			Agents::<T>::contains_key(id)
+		}
 	}
 
 	impl<T: Config> StaticLookup for Pallet<T> {
@@ -679,6 +742,12 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> Contains<AgentId> for Pallet<T> {
+		fn contains(agent_id: &AgentId) -> bool {
+			Self::agent_exists(*agent_id)
+		}
+	}
+
 	impl<T: Config> Get<PricingParametersOf<T>> for Pallet<T> {
 		fn get() -> PricingParametersOf<T> {
 			PricingParameters::<T>::get()
@@ -198,6 +198,8 @@ parameter_types! {
 		multiplier: FixedU128::from_rational(4, 3)
 	};
 	pub const InboundDeliveryCost: u128 = 1_000_000_000;
+	pub MinExchangeRate: FixedU128 = FixedU128::from_rational(1, 10_000);
+	pub MaxExchangeRate: FixedU128 = FixedU128::from_rational(1, 10);
 
 }
 
@@ -218,6 +220,8 @@ impl crate::Config for Test {
 	type DefaultPricingParameters = Parameters;
 	type WeightInfo = ();
 	type InboundDeliveryCost = InboundDeliveryCost;
+	type MinExchangeRate = MinExchangeRate;
+	type MaxExchangeRate = MaxExchangeRate;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 }
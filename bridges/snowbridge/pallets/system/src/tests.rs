@@ -27,6 +27,24 @@ fn create_agent() {
 	});
 }
 
+#[test]
+fn agent_exists() {
+	new_test_ext(true).execute_with(|| {
+		let origin_location = Location::new(1, [Parachain(2000)]);
+		let agent_id = make_agent_id(origin_location.clone());
+
+		assert!(!EthereumSystem::agent_exists(agent_id));
+		assert!(!<EthereumSystem as Contains<AgentId>>::contains(&agent_id));
+
+		assert_ok!(EthereumSystem::create_agent(make_xcm_origin(origin_location)));
+
+		assert!(EthereumSystem::agent_exists(agent_id));
+		assert!(<EthereumSystem as Contains<AgentId>>::contains(&agent_id));
+		// agents and channels are looked up separately.
+		assert!(!<EthereumSystem as Contains<ChannelId>>::contains(&ParaId::from(2000).into()));
+	});
+}
+
 #[test]
 fn test_agent_for_here() {
 	new_test_ext(true).execute_with(|| {
@@ -206,6 +224,35 @@ fn set_pricing_parameters_invalid() {
 	});
 }
 
+#[test]
+fn set_pricing_parameters_exchange_rate_out_of_range() {
+	new_test_ext(true).execute_with(|| {
+		let origin = RuntimeOrigin::root();
+
+		let mut params = Parameters::get();
+		params.exchange_rate = sp_runtime::FixedU128::from_rational(1, 100_000);
+		assert_noop!(
+			EthereumSystem::set_pricing_parameters(origin.clone(), params),
+			Error::<Test>::InvalidPricingParameters
+		);
+
+		let mut params = Parameters::get();
+		params.exchange_rate = sp_runtime::FixedU128::from_rational(1, 2);
+		assert_noop!(
+			EthereumSystem::set_pricing_parameters(origin.clone(), params),
+			Error::<Test>::InvalidPricingParameters
+		);
+
+		// The bounds themselves are accepted.
+		let mut params = Parameters::get();
+		params.exchange_rate = MinExchangeRate::get();
+		assert_ok!(EthereumSystem::set_pricing_parameters(origin.clone(), params));
+		let mut params = Parameters::get();
+		params.exchange_rate = MaxExchangeRate::get();
+		assert_ok!(EthereumSystem::set_pricing_parameters(origin, params));
+	});
+}
+
 #[test]
 fn set_token_transfer_fees() {
 	new_test_ext(true).execute_with(|| {
@@ -532,6 +579,51 @@ fn force_transfer_native_from_agent_bad_origin() {
 	});
 }
 
+#[test]
+fn set_fee_account() {
+	new_test_ext(true).execute_with(|| {
+		let account: AccountId = [14; 32].into();
+		assert_eq!(EthereumSystem::fee_account(), TreasuryAccount::get());
+
+		assert_ok!(EthereumSystem::set_fee_account(RuntimeOrigin::root(), account.clone()));
+
+		assert_eq!(EthereumSystem::fee_account(), account);
+		System::assert_last_event(RuntimeEvent::EthereumSystem(crate::Event::FeeAccountChanged {
+			account,
+		}));
+	});
+}
+
+#[test]
+fn set_fee_account_root_only() {
+	new_test_ext(true).execute_with(|| {
+		let origin = RuntimeOrigin::signed([14; 32].into());
+
+		assert_noop!(EthereumSystem::set_fee_account(origin, [14; 32].into()), BadOrigin);
+	});
+}
+
+#[test]
+fn charge_fee_to_fee_account_override() {
+	new_test_ext(true).execute_with(|| {
+		let para_id: u32 = TestParaId::get();
+		let origin = make_xcm_origin(Location::new(1, [Parachain(para_id)]));
+		let sovereign_account = sibling_sovereign_account::<Test>(para_id.into());
+		let fee_account: AccountId = [14; 32].into();
+		assert_ok!(EthereumSystem::set_fee_account(RuntimeOrigin::root(), fee_account.clone()));
+
+		let initial_sovereign_balance = Balances::balance(&sovereign_account);
+		let initial_treasury_balance = Balances::balance(&TreasuryAccount::get());
+		assert_ok!(EthereumSystem::create_agent(origin));
+		let fee_charged = initial_sovereign_balance - Balances::balance(&sovereign_account);
+
+		// the fee goes to the override instead of the treasury
+		assert!(fee_charged > 0);
+		assert_eq!(Balances::balance(&fee_account), fee_charged);
+		assert_eq!(Balances::balance(&TreasuryAccount::get()), initial_treasury_balance);
+	});
+}
+
 // NOTE: The following tests are not actually tests and are more about obtaining location
 // conversions for devops purposes. They need to be removed here and incorporated into a command
 // line utility.
@@ -618,6 +710,27 @@ fn charge_fee_for_upgrade() {
 	});
 }
 
+#[test]
+fn quote_command_fee_works() {
+	new_test_ext(true).execute_with(|| {
+		let command = Command::SetOperatingMode { mode: OperatingMode::RejectingOutboundMessages };
+		let message =
+			Message { id: None, channel_id: PRIMARY_GOVERNANCE_CHANNEL, command: command.clone() };
+		let (_, fee) = OutboundQueue::validate(&message).unwrap();
+
+		assert_eq!(
+			EthereumSystem::quote_command_fee(PRIMARY_GOVERNANCE_CHANNEL, command.clone()),
+			Ok((fee.local, fee.total()))
+		);
+
+		// no fee can be quoted for an unknown channel.
+		assert_eq!(
+			EthereumSystem::quote_command_fee(ParaId::from(3000).into(), command),
+			Err(Error::<Test>::Send(SendError::InvalidChannel))
+		);
+	});
+}
+
 #[test]
 fn genesis_build_initializes_correctly() {
 	new_test_ext(true).execute_with(|| {
@@ -42,6 +42,7 @@ pub trait WeightInfo {
 	fn force_transfer_native_from_agent() -> Weight;
 	fn set_token_transfer_fees() -> Weight;
 	fn set_pricing_parameters() -> Weight;
+	fn set_fee_account() -> Weight;
 }
 
 // For backwards compatibility and tests.
@@ -246,4 +247,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+
+	/// Storage: EthereumSystem FeeAccountOverride (r:0 w:1)
+	/// Proof: EthereumSystem FeeAccountOverride (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	fn set_fee_account() -> Weight {
+		// Placeholder, not benchmark output: regenerate with the benchmark CLI.
+		Weight::from_parts(6_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
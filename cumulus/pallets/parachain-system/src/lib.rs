@@ -994,6 +994,11 @@ impl<T: Config> Pallet<T> {
 		let segment = UnincludedSegment::<T>::get();
 		crate::unincluded_segment::size_after_included(included_hash, &segment)
 	}
+
+	/// Get the number of blocks in the unincluded segment.
+	pub fn unincluded_segment_len() -> u32 {
+		UnincludedSegment::<T>::decode_len().unwrap_or(0) as u32
+	}
 }
 
 impl<T: Config> FeeTracker for Pallet<T> {
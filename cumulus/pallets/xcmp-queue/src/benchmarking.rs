@@ -39,6 +39,17 @@ mod benchmarks {
 		Pallet::<T>::update_resume_threshold(RawOrigin::Root, 1);
 	}
 
+	#[benchmark]
+	fn set_channel_config() {
+		let para = 123.into();
+		let config = QueueConfigData::default();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, para, config);
+
+		assert_eq!(ChannelQueueConfig::<T>::get(para), Some(config));
+	}
+
 	#[benchmark]
 	fn enqueue_xcmp_message() {
 		assert!(QueueConfig::<T>::get().drop_threshold * MaxXcmpMessageLenOf::<T>::get() > 1000);
@@ -90,32 +101,126 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn force_resume_inbound_channel() {
+		let para = 123.into();
+		InboundXcmpSuspended::<T>::mutate(|s| s.try_insert(para).unwrap());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, para);
+
+		assert!(InboundXcmpSuspended::<T>::get().is_empty());
+		assert!(SignalMessages::<T>::contains_key(para));
+	}
+
+	#[benchmark]
+	fn decay_delivery_fee() {
+		let para = 123.into();
+		DeliveryFeeFactor::<T>::insert(para, FixedU128::from_u32(2));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, para);
+
+		assert!(DeliveryFeeFactor::<T>::get(para) < FixedU128::from_u32(2));
+	}
+
+	#[benchmark]
+	fn discard_oversize_outbound() {
+		let para = 123.into();
+		let page = vec![0u8; MaxXcmpMessageLenOf::<T>::get() as usize];
+		OutboundXcmpMessages::<T>::insert(para, 1, page);
+		OutboundXcmpStatus::<T>::put(vec![OutboundChannelDetails {
+			recipient: para,
+			state: OutboundState::Ok,
+			signals_exist: false,
+			first_index: 1,
+			last_index: 2,
+		}]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, para, 1);
+
+		assert!(!OutboundXcmpMessages::<T>::contains_key(para, 1));
+	}
+
+	/// Compact `n` outbound pages that each hold a single XCM into one page.
+	///
+	/// Skipped if the runtime does not report a channel to the recipient.
+	#[benchmark]
+	fn compact_channel(n: Linear<1, 100>) -> Result<(), BenchmarkError> {
+		let para = 7777.into();
+		if T::ChannelInfo::get_channel_info(para).is_none() {
+			return Err(BenchmarkError::Skip)
+		}
+
+		let xcm = VersionedXcm::<()>::from(Xcm::<()>(vec![ClearOrigin]));
+		for page_index in 0..n as u16 {
+			let mut page = XcmpMessageFormat::ConcatenatedVersionedXcm.encode();
+			xcm.using_encoded(|m| page.extend_from_slice(m));
+			OutboundXcmpMessages::<T>::insert(para, page_index, page);
+		}
+		OutboundXcmpStatus::<T>::put(vec![OutboundChannelDetails {
+			recipient: para,
+			state: OutboundState::Ok,
+			signals_exist: false,
+			first_index: 0,
+			last_index: n as u16,
+		}]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, para, n);
+
+		assert_eq!(Pallet::<T>::outbound_queued_pages(para), 1);
+		Ok(())
+	}
+
+	/// Import a snapshot with `n` pages over a current outbound state with `n` other pages.
+	#[benchmark]
+	fn import_outbound_state(n: Linear<0, 1000>) {
+		let queue_pages = |para: ParaId| {
+			for page_index in 0..n as u16 {
+				OutboundXcmpMessages::<T>::insert(para, page_index, vec![0u8; 64]);
+			}
+			OutboundXcmpStatus::<T>::put(vec![OutboundChannelDetails {
+				recipient: para,
+				state: OutboundState::Ok,
+				signals_exist: false,
+				first_index: 0,
+				last_index: n as u16,
+			}]);
+		};
+		queue_pages(1.into());
+		let blob = Pallet::<T>::export_outbound_state();
+		let _ = OutboundXcmpMessages::<T>::clear(u32::MAX, None);
+		queue_pages(2.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, blob.clone().try_into().unwrap(), n);
+
+		assert_eq!(Pallet::<T>::export_outbound_state(), blob);
+	}
+
 	/// Split a singular XCM.
 	#[benchmark]
 	fn take_first_concatenated_xcm() {
 		let max_downward_message_size = MaxXcmpMessageLenOf::<T>::get() as usize;
+		let max_depth = T::MaxXcmDecodeDepth::get();
 
-		assert!(MAX_INSTRUCTIONS_TO_DECODE as u32 > MAX_XCM_DECODE_DEPTH, "Preconditon failed");
-		let max_instrs = MAX_INSTRUCTIONS_TO_DECODE as u32 - MAX_XCM_DECODE_DEPTH;
+		assert!(MAX_INSTRUCTIONS_TO_DECODE as u32 > max_depth, "Preconditon failed");
+		let max_instrs = MAX_INSTRUCTIONS_TO_DECODE as u32 - max_depth;
 		let mut xcm = Xcm::<T>(vec![ClearOrigin; max_instrs as usize]);
 
-		for _ in 0..MAX_XCM_DECODE_DEPTH - 1 {
+		for _ in 0..max_depth - 1 {
 			xcm = Xcm::<T>(vec![Instruction::SetAppendix(xcm)]);
 		}
 
 		let data = VersionedXcm::<T>::from(xcm).encode();
 		assert!(data.len() < max_downward_message_size, "Page size is too small");
 		// Verify that decoding works with the exact recursion limit:
-		VersionedXcm::<T::RuntimeCall>::decode_with_depth_limit(
-			MAX_XCM_DECODE_DEPTH,
-			&mut &data[..],
-		)
-		.unwrap();
-		VersionedXcm::<T::RuntimeCall>::decode_with_depth_limit(
-			MAX_XCM_DECODE_DEPTH - 1,
-			&mut &data[..],
-		)
-		.unwrap_err();
+		VersionedXcm::<T::RuntimeCall>::decode_with_depth_limit(max_depth, &mut &data[..])
+			.unwrap();
+		VersionedXcm::<T::RuntimeCall>::decode_with_depth_limit(max_depth - 1, &mut &data[..])
+			.unwrap_err();
 
 		#[block]
 		{
@@ -124,6 +229,20 @@ mod benchmarks {
 		}
 	}
 
+	/// Split a singular maximal sized blob.
+	#[benchmark]
+	fn process_blob() {
+		let max_blob_size = MaxXcmpMessageLenOf::<T>::get() as usize;
+		// Leave space for the compact length prefix.
+		let data = vec![0u8; max_blob_size.saturating_sub(5)].encode();
+
+		#[block]
+		{
+			Pallet::<T>::take_first_concatenated_blob(&mut &data[..], &mut WeightMeter::new())
+				.unwrap();
+		}
+	}
+
 	/// Benchmark the migration for a maximal sized message.
 	#[benchmark]
 	fn on_idle_good_msg() {
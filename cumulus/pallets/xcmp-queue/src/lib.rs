@@ -51,15 +51,16 @@ pub mod weights;
 pub use weights::WeightInfo;
 
 use bounded_collections::BoundedBTreeSet;
-use codec::{Decode, DecodeLimit, Encode};
+use codec::{Decode, DecodeAll, DecodeLimit, Encode};
 use cumulus_primitives_core::{
 	relay_chain::BlockNumber as RelayBlockNumber, ChannelStatus, GetChannelInfo, MessageSendError,
 	ParaId, XcmpMessageFormat, XcmpMessageHandler, XcmpMessageSource,
 };
+pub use cumulus_primitives_core::OutboundState;
 
 use frame_support::{
 	defensive, defensive_assert,
-	traits::{EnqueueMessage, EnsureOrigin, Get, QueueFootprint, QueuePausedQuery},
+	traits::{Contains, EnqueueMessage, EnsureOrigin, Get, QueueFootprint, QueuePausedQuery},
 	weights::{Weight, WeightMeter},
 	BoundedVec,
 };
@@ -68,8 +69,11 @@ use polkadot_runtime_common::xcm_sender::PriceForMessageDelivery;
 use polkadot_runtime_parachains::FeeTracker;
 use scale_info::TypeInfo;
 use sp_core::MAX_POSSIBLE_ALLOCATION;
-use sp_runtime::{FixedU128, RuntimeDebug, Saturating};
-use sp_std::prelude::*;
+use sp_runtime::{traits::BlockNumberProvider, FixedU128, RuntimeDebug, Saturating};
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	prelude::*,
+};
 use xcm::{latest::prelude::*, VersionedXcm, WrapVersion, MAX_XCM_DECODE_DEPTH};
 use xcm_executor::traits::ConvertOrigin;
 
@@ -83,6 +87,10 @@ pub type MaxXcmpMessageLenOf<T> =
 
 const LOG_TARGET: &str = "xcmp_queue";
 const DEFAULT_POV_SIZE: u64 = 64 * 1024; // 64 KB
+/// The minimal encoded size of a page or signal in an [`OutboundStateSnapshot`].
+const MIN_SNAPSHOT_ITEM_SIZE: u32 = 5;
+/// The maximal encoded size of an [`OutboundStateSnapshot`] that can be imported.
+pub const MAX_OUTBOUND_STATE_SIZE: u32 = 2 * 1024 * 1024;
 
 /// Constants related to delivery fee calculation
 pub mod delivery_fee_constants {
@@ -97,6 +105,12 @@ pub mod delivery_fee_constants {
 	pub const MESSAGE_SIZE_FEE_BASE: FixedU128 = FixedU128::from_rational(1, 1000); // 0.001
 }
 
+frame_support::parameter_types! {
+	/// A sensible default for [`Config::MaxDeliveryFeeFactor`]; delivery fees can grow to at most
+	/// 100 times the base fee.
+	pub const DefaultMaxDeliveryFeeFactor: FixedU128 = FixedU128::from_u32(100);
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -132,6 +146,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxInboundSuspended: Get<u32>;
 
+		/// The maximum number of messages from a single sender that are processed in one block
+		/// before the other senders had their turn.
+		///
+		/// Pages of a sender that reached this limit are deferred, not dropped: they are handled
+		/// after the pages of all other senders, within the same block.
+		#[pallet::constant]
+		type MaxMessagesPerSenderPerBlock: Get<u32>;
+
+		/// The maximum depth to which nested inbound XCMs are decoded.
+		///
+		/// Messages that are nested deeper are dropped. [`MAX_XCM_DECODE_DEPTH`] is a sensible
+		/// default.
+		#[pallet::constant]
+		type MaxXcmDecodeDepth: Get<u32>;
+
 		/// The origin that is allowed to resume or suspend the XCMP queue.
 		type ControllerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -142,6 +171,54 @@ pub mod pallet {
 		/// The price for delivering an XCM to a sibling parachain destination.
 		type PriceForSiblingDelivery: PriceForMessageDelivery<Id = ParaId>;
 
+		/// Whether outbound XCM pages should carry a CRC32 checksum of their content.
+		///
+		/// When enabled, pages are sent with the [`XcmpMessageFormat::ChecksummedVersionedXcm`]
+		/// format so that the receiver can detect corrupted pages. Inbound checksummed pages are
+		/// always verified, regardless of this setting.
+		#[pallet::constant]
+		type EmitPageChecksums: Get<bool>;
+
+		/// Handles the blobs of inbound [`XcmpMessageFormat::ConcatenatedEncodedBlob`] pages.
+		///
+		/// Use `()` to drop all blobs.
+		type BlobHandler: HandleBlobMessage;
+
+		/// The maximum number of outbound channels that can have a signal queued simultaneously.
+		///
+		/// Further signals to channels without a queued signal are rejected until the queued ones
+		/// are delivered.
+		#[pallet::constant]
+		type MaxQueuedSignals: Get<u32>;
+
+		/// The upper bound of the [`DeliveryFeeFactor`] of any channel.
+		///
+		/// Prevents the delivery fees of a congested channel from growing without limit. See
+		/// [`DefaultMaxDeliveryFeeFactor`] for a sensible default.
+		#[pallet::constant]
+		type MaxDeliveryFeeFactor: Get<FixedU128>;
+
+		/// The siblings whose outbound channels are prioritized, e.g. for governance relays.
+		///
+		/// The [`DeliveryFeeFactor`] of a priority channel is not increased when it gets
+		/// congested, and priority channels are serviced before all others when outbound pages
+		/// are taken. Since a priority channel is neither priced out nor delayed by other
+		/// channels, its pages may keep taking up the PoV space of each block at the expense of
+		/// the other channels. Only use it for low-volume destinations. Use `Nothing` to not
+		/// prioritize any channel.
+		type PriorityDestinations: Contains<ParaId>;
+
+		/// Whether [`Event::XcmpMessageSentAt`] should be emitted along with
+		/// [`Event::XcmpMessageSent`], carrying the relay chain block number at which the message
+		/// was sent.
+		#[pallet::constant]
+		type EmitSentAt: Get<bool>;
+
+		/// Provides the relay chain block number for [`Event::XcmpMessageSentAt`].
+		///
+		/// Only used if [`Config::EmitSentAt`] is enabled.
+		type RelayBlockNumberProvider: BlockNumberProvider<BlockNumber = RelayBlockNumber>;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -233,6 +310,266 @@ pub mod pallet {
 				data.validate::<T>()
 			})
 		}
+
+		/// Overwrites the queue configuration of the channel with the sibling `para`.
+		///
+		/// The per-channel configuration takes precedence over the global `QueueConfig`.
+		///
+		/// - `origin`: Must pass `ControllerOrigin`.
+		/// - `para`: The sibling whose channel is configured.
+		/// - `config`: The thresholds to use for the channel.
+		#[pallet::call_index(6)]
+		#[pallet::weight((T::WeightInfo::set_channel_config(), DispatchClass::Operational,))]
+		pub fn set_channel_config(
+			origin: OriginFor<T>,
+			para: ParaId,
+			config: QueueConfigData,
+		) -> DispatchResult {
+			T::ControllerOrigin::ensure_origin(origin)?;
+
+			config.validate::<T>()?;
+			ChannelQueueConfig::<T>::insert(para, config);
+			Ok(())
+		}
+
+		/// Resumes the inbound channel from the sibling `sender`, regardless of its queue.
+		///
+		/// Sends `sender` a resume signal again and removes it from the suspended inbound
+		/// channels. Fails with `TooManyQueuedSignals` if the signal cannot be queued, leaving the
+		/// channel suspended. Can be used to recover a channel that got stuck in the suspended
+		/// state.
+		///
+		/// - `origin`: Must pass `ControllerOrigin`.
+		/// - `sender`: The sibling whose channel should be resumed.
+		#[pallet::call_index(7)]
+		#[pallet::weight((
+			T::WeightInfo::force_resume_inbound_channel(),
+			DispatchClass::Operational,
+		))]
+		pub fn force_resume_inbound_channel(
+			origin: OriginFor<T>,
+			sender: ParaId,
+		) -> DispatchResult {
+			T::ControllerOrigin::ensure_origin(origin)?;
+
+			Self::send_signal(sender, ChannelSignal::Resume)?;
+			<InboundXcmpSuspended<T>>::mutate(|suspended| suspended.remove(&sender));
+
+			Self::deposit_event(Event::InboundChannelForceResumed { sender });
+			Ok(())
+		}
+
+		/// Discards the oversize page `page_index` queued for delivery to the sibling `recipient`.
+		///
+		/// A page is oversize if it exceeds the maximum message size of the channel, for example
+		/// after the channel was reconfigured. Such pages can never be delivered and would
+		/// otherwise be dropped silently once they reach the head of the queue. Only the first and
+		/// the last queued page can be discarded, so that the order of the remaining pages is
+		/// kept.
+		///
+		/// - `origin`: Must pass `ControllerOrigin`.
+		/// - `recipient`: The sibling the page is queued for.
+		/// - `page_index`: The index of the page in `OutboundXcmpMessages`.
+		#[pallet::call_index(8)]
+		#[pallet::weight((T::WeightInfo::discard_oversize_outbound(), DispatchClass::Operational))]
+		pub fn discard_oversize_outbound(
+			origin: OriginFor<T>,
+			recipient: ParaId,
+			page_index: u16,
+		) -> DispatchResult {
+			T::ControllerOrigin::ensure_origin(origin)?;
+
+			let mut statuses = <OutboundXcmpStatus<T>>::get();
+			let details = statuses
+				.iter_mut()
+				.find(|item| item.recipient == recipient)
+				.filter(|d| d.first_index <= page_index && page_index < d.last_index)
+				.ok_or(Error::<T>::UnknownPage)?;
+			ensure!(
+				page_index == details.first_index || page_index == details.last_index - 1,
+				Error::<T>::PageNotAtQueueEdge
+			);
+
+			// Pages of channels that are not open cannot be delivered at all.
+			let page_len = <OutboundXcmpMessages<T>>::decode_len(recipient, page_index)
+				.ok_or(Error::<T>::UnknownPage)?;
+			let oversize = T::ChannelInfo::get_channel_info(recipient)
+				.map_or(true, |info| page_len > info.max_message_size as usize);
+			ensure!(oversize, Error::<T>::NotOversizePage);
+
+			<OutboundXcmpMessages<T>>::remove(recipient, page_index);
+			if page_index == details.first_index {
+				details.first_index += 1;
+			} else {
+				details.last_index -= 1;
+			}
+			if details.first_index == details.last_index {
+				details.first_index = 0;
+				details.last_index = 0;
+			}
+			<OutboundXcmpStatus<T>>::put(statuses);
+
+			Self::deposit_event(Event::OversizeMessageDiscarded { recipient, page_index });
+			Ok(())
+		}
+
+		/// Re-packs up to `max_pages` queued outbound pages to the sibling `recipient` densely.
+		///
+		/// Pages may only be partially filled, for example if the format of the messages changed
+		/// in between. The fragments of the first `max_pages` pages are re-packed into as few
+		/// pages as possible, keeping their order and format. Nothing happens if the pages cannot
+		/// be packed any denser.
+		///
+		/// - `origin`: Must pass `ControllerOrigin`.
+		/// - `recipient`: The sibling whose outbound queue is compacted.
+		/// - `max_pages`: The maximal number of pages to compact, starting at the first one.
+		#[pallet::call_index(9)]
+		#[pallet::weight((T::WeightInfo::compact_channel(*max_pages), DispatchClass::Operational))]
+		pub fn compact_channel(
+			origin: OriginFor<T>,
+			recipient: ParaId,
+			max_pages: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ControllerOrigin::ensure_origin(origin)?;
+
+			let max_message_size = T::ChannelInfo::get_channel_info(recipient)
+				.ok_or(Error::<T>::UnknownChannel)?
+				.max_message_size as usize;
+			let mut statuses = <OutboundXcmpStatus<T>>::get();
+			let details = statuses
+				.iter_mut()
+				.find(|item| item.recipient == recipient)
+				.ok_or(Error::<T>::UnknownChannel)?;
+
+			let first_index = details.first_index;
+			let max_pages = max_pages.min(u16::MAX as u32) as u16;
+			let end_index = details.last_index.min(first_index.saturating_add(max_pages));
+			let old_pages = end_index - first_index;
+
+			let mut new_pages: Vec<(XcmpMessageFormat, Vec<u8>)> = Vec::new();
+			for page_index in first_index..end_index {
+				let page = <OutboundXcmpMessages<T>>::get(recipient, page_index);
+				let format = XcmpMessageFormat::decode(&mut &page[..])
+					.map_err(|_| Error::<T>::BadOutboundPage)?;
+				let body = page.get(Self::page_header_size(format)..).unwrap_or_default();
+				for fragment in Self::split_page_fragments(format, body)? {
+					match new_pages.last_mut() {
+						Some((f, new_page)) if *f == format &&
+							new_page.len() + fragment.len() <= max_message_size =>
+							new_page.extend_from_slice(fragment),
+						_ => {
+							let mut new_page = format.encode();
+							new_page.resize(Self::page_header_size(format), 0);
+							new_page.extend_from_slice(fragment);
+							new_pages.push((format, new_page));
+						},
+					}
+				}
+			}
+
+			let weight = T::WeightInfo::compact_channel(old_pages as u32);
+			if new_pages.len() >= old_pages as usize {
+				return Ok(Some(weight).into())
+			}
+
+			// The compacted pages end where the old ones ended, so that the pages after them
+			// keep their indices.
+			let new_first_index = end_index - new_pages.len() as u16;
+			for page_index in first_index..end_index {
+				<OutboundXcmpMessages<T>>::remove(recipient, page_index);
+			}
+			for (page_index, (format, mut page)) in (new_first_index..).zip(new_pages) {
+				Self::update_page_checksum(format, &mut page);
+				<OutboundXcmpMessages<T>>::insert(recipient, page_index, page);
+			}
+			details.first_index = new_first_index;
+			<OutboundXcmpStatus<T>>::put(statuses);
+
+			Self::deposit_event(Event::OutboundChannelCompacted {
+				recipient,
+				old_pages,
+				new_pages: end_index - new_first_index,
+			});
+			Ok(Some(weight).into())
+		}
+
+		/// Replace the whole outbound state with a snapshot of [`Pallet::export_outbound_state`].
+		///
+		/// The snapshot must be consistent: exactly the pages between the indices of each channel
+		/// must be queued, and signals must be queued for exactly the channels that flag them.
+		/// All currently queued pages and signals are discarded.
+		///
+		/// - `origin`: Must pass `ControllerOrigin`.
+		/// - `blob`: The SCALE-encoded [`OutboundStateSnapshot`].
+		/// - `queued_items`: An upper bound of the number of pages and signals that are currently
+		///   queued. Fails with [`Error::BadWitness`] if more are queued.
+		#[pallet::call_index(10)]
+		#[pallet::weight((
+			T::WeightInfo::import_outbound_state(
+				(blob.len() as u32 / MIN_SNAPSHOT_ITEM_SIZE).max(*queued_items)
+			),
+			DispatchClass::Operational,
+		))]
+		pub fn import_outbound_state(
+			origin: OriginFor<T>,
+			blob: BoundedVec<u8, ConstU32<MAX_OUTBOUND_STATE_SIZE>>,
+			queued_items: u32,
+		) -> DispatchResultWithPostInfo {
+			T::ControllerOrigin::ensure_origin(origin)?;
+
+			let snapshot = OutboundStateSnapshot::decode_all(&mut &blob[..])
+				.map_err(|_| Error::<T>::BadOutboundState)?;
+			ensure!(snapshot.is_consistent(), Error::<T>::BadOutboundState);
+
+			let queued = <OutboundXcmpStatus<T>>::get().iter().fold(0u32, |queued, c| {
+				let pages = c.last_index.saturating_sub(c.first_index) as u32;
+				queued.saturating_add(pages).saturating_add(c.signals_exist as u32)
+			});
+			ensure!(queued <= queued_items, Error::<T>::BadWitness);
+
+			let pages = <OutboundXcmpMessages<T>>::clear(queued_items, None);
+			let signals =
+				<SignalMessages<T>>::clear(queued_items.saturating_sub(pages.unique), None);
+			ensure!(
+				pages.maybe_cursor.is_none() && signals.maybe_cursor.is_none(),
+				Error::<T>::BadWitness
+			);
+			let removed = pages.unique.saturating_add(signals.unique);
+
+			let channels = snapshot.channels.len() as u32;
+			let items = (snapshot.pages.len() + snapshot.signals.len()) as u32;
+			for (recipient, page_index, page) in snapshot.pages {
+				<OutboundXcmpMessages<T>>::insert(recipient, page_index, page);
+			}
+			for (recipient, signal) in snapshot.signals {
+				<SignalMessages<T>>::insert(recipient, signal);
+			}
+			<OutboundXcmpStatus<T>>::put(snapshot.channels);
+
+			Self::deposit_event(Event::OutboundStateImported { channels, items });
+			Ok(Some(T::WeightInfo::import_outbound_state(items.max(removed))).into())
+		}
+
+		/// Applies one decay step to the [`DeliveryFeeFactor`] of the channel to `recipient`.
+		///
+		/// The factor is otherwise only decreased while the channel is being serviced, so this can
+		/// be used to relieve an elevated delivery fee of a channel that stopped being serviced.
+		/// The factor never drops below [`InitialFactor`].
+		///
+		/// - `origin`: Must pass `ControllerOrigin`.
+		/// - `recipient`: The sibling whose delivery fee factor is decreased.
+		#[pallet::call_index(11)]
+		#[pallet::weight((T::WeightInfo::decay_delivery_fee(), DispatchClass::Operational,))]
+		pub fn decay_delivery_fee(origin: OriginFor<T>, recipient: ParaId) -> DispatchResult {
+			T::ControllerOrigin::ensure_origin(origin)?;
+
+			let delivery_fee_factor = <Self as FeeTracker>::decrease_fee_factor(recipient);
+			Self::deposit_event(Event::DeliveryFeeFactorDecayed {
+				recipient,
+				delivery_fee_factor,
+			});
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -241,6 +578,11 @@ pub mod pallet {
 			let w = Self::on_idle_weight();
 			assert!(w != Weight::zero());
 			assert!(w.all_lte(T::BlockWeights::get().max_block));
+			assert!(T::MaxQueuedSignals::get() > 0, "MaxQueuedSignals must be non-zero");
+			assert!(
+				T::MaxDeliveryFeeFactor::get() >= InitialFactor::get(),
+				"MaxDeliveryFeeFactor must not be below the initial factor"
+			);
 		}
 
 		fn on_idle(_block: BlockNumberFor<T>, limit: Weight) -> Weight {
@@ -266,6 +608,35 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// An HRMP message was sent to a sibling parachain.
 		XcmpMessageSent { message_hash: XcmHash },
+		/// An inbound page was dropped since its checksum did not match its content.
+		PageChecksumMismatch { sender: ParaId },
+		/// Inbound XCMP messages from `sender` were dropped.
+		///
+		/// `count` is the number of XCMs known to be lost; the remainder of the page is dropped
+		/// as well.
+		InboundXcmpMessagesDropped { sender: ParaId, count: u32, reason: DropReason },
+		/// The remainder of an inbound page was dropped since it switched to another format.
+		MixedFormatPage { sender: ParaId },
+		/// The inbound channel from `sender` was resumed by the `ControllerOrigin`.
+		InboundChannelForceResumed { sender: ParaId },
+		/// A signal to `recipient` was not queued since too many channels already have a signal
+		/// queued.
+		SignalQueueFull { recipient: ParaId },
+		/// A signal was queued for delivery to the sibling `dest`.
+		SignalSent { dest: ParaId, signal: ChannelSignal },
+		/// The oversize outbound page `page_index` to `recipient` was discarded.
+		OversizeMessageDiscarded { recipient: ParaId, page_index: u16 },
+		/// `old_pages` outbound pages to `recipient` were compacted into `new_pages` pages.
+		OutboundChannelCompacted { recipient: ParaId, old_pages: u16, new_pages: u16 },
+		/// The outbound state of `channels` channels with `items` pages and signals was imported.
+		OutboundStateImported { channels: u32, items: u32 },
+		/// The delivery fee factor of the channel to `recipient` was decayed by the
+		/// `ControllerOrigin` to `delivery_fee_factor`.
+		DeliveryFeeFactorDecayed { recipient: ParaId, delivery_fee_factor: FixedU128 },
+		/// The HRMP message `message_hash` was sent at the relay chain block number `sent_at`.
+		///
+		/// Only emitted if [`Config::EmitSentAt`] is enabled.
+		XcmpMessageSentAt { message_hash: XcmHash, sent_at: RelayBlockNumber },
 	}
 
 	#[pallet::error]
@@ -276,6 +647,22 @@ pub mod pallet {
 		AlreadySuspended,
 		/// The execution is already resumed.
 		AlreadyResumed,
+		/// Too many channels have a signal queued already.
+		TooManyQueuedSignals,
+		/// The outbound page does not exist.
+		UnknownPage,
+		/// The outbound page is neither the first nor the last queued page of its channel.
+		PageNotAtQueueEdge,
+		/// The outbound page does not exceed the maximum message size of its channel.
+		NotOversizePage,
+		/// There is no outbound channel to the recipient.
+		UnknownChannel,
+		/// An outbound page could not be decoded.
+		BadOutboundPage,
+		/// The outbound state snapshot could not be decoded or is inconsistent.
+		BadOutboundState,
+		/// More outbound pages and signals are queued than the witness allows for.
+		BadWitness,
 	}
 
 	/// The suspended inbound XCMP channels. All others are not suspended.
@@ -315,6 +702,11 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type QueueConfig<T: Config> = StorageValue<_, QueueConfigData, ValueQuery>;
 
+	/// Per-channel overrides of [`QueueConfig`].
+	#[pallet::storage]
+	pub(super) type ChannelQueueConfig<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, QueueConfigData, OptionQuery>;
+
 	/// Whether or not the XCMP queue is suspended from executing incoming XCMs or not.
 	#[pallet::storage]
 	pub(super) type QueueSuspended<T: Config> = StorageValue<_, bool, ValueQuery>;
@@ -329,12 +721,12 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type DeliveryFeeFactor<T: Config> =
 		StorageMap<_, Twox64Concat, ParaId, FixedU128, ValueQuery, InitialFactor>;
-}
 
-#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
-pub enum OutboundState {
-	Ok,
-	Suspended,
+	/// The number of inbound messages dropped per sibling, since they ran out of weight or could
+	/// not be decoded.
+	#[pallet::storage]
+	pub(super) type DroppedMessageCount<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, u32, ValueQuery>;
 }
 
 /// Struct containing detailed information about the outbound channel.
@@ -375,6 +767,47 @@ impl OutboundChannelDetails {
 	}
 }
 
+/// A snapshot of the whole outbound state of the pallet.
+///
+/// Created by [`Pallet::export_outbound_state`] and restored by
+/// [`Pallet::import_outbound_state`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct OutboundStateSnapshot {
+	/// The details of all outbound channels.
+	pub channels: Vec<OutboundChannelDetails>,
+	/// All queued outbound pages by recipient and page index.
+	pub pages: Vec<(ParaId, u16, Vec<u8>)>,
+	/// All queued signals by recipient.
+	pub signals: Vec<(ParaId, Vec<u8>)>,
+}
+
+impl OutboundStateSnapshot {
+	/// Whether the queued pages and signals match the indices and flags of the channels.
+	pub fn is_consistent(&self) -> bool {
+		let mut recipients = BTreeSet::new();
+		let mut expected_pages = BTreeSet::new();
+		let mut expected_signals = BTreeSet::new();
+		for channel in &self.channels {
+			if !recipients.insert(channel.recipient) || channel.first_index > channel.last_index {
+				return false
+			}
+			expected_pages
+				.extend((channel.first_index..channel.last_index).map(|i| (channel.recipient, i)));
+			if channel.signals_exist {
+				expected_signals.insert(channel.recipient);
+			}
+		}
+
+		let pages = self.pages.iter().map(|(para, i, _)| (*para, *i)).collect::<BTreeSet<_>>();
+		let signals = self.signals.iter().map(|(para, _)| *para).collect::<BTreeSet<_>>();
+		pages.len() == self.pages.len() &&
+			signals.len() == self.signals.len() &&
+			pages == expected_pages &&
+			signals == expected_signals
+	}
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct QueueConfigData {
 	/// The number of pages which must be in the queue for the other side to be told to suspend
@@ -417,12 +850,42 @@ impl QueueConfigData {
 	}
 }
 
-#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, TypeInfo)]
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
 pub enum ChannelSignal {
 	Suspend,
 	Resume,
 }
 
+/// Handles the raw blobs that siblings send with the
+/// [`XcmpMessageFormat::ConcatenatedEncodedBlob`] format.
+pub trait HandleBlobMessage {
+	/// Handle a single `blob` sent by the sibling `sender`.
+	///
+	/// The pallet only accounts for splitting the blob off its page. Any further weight must be
+	/// consumed from `meter`. Returning an error drops the remainder of the page.
+	fn handle_blob_message(
+		sender: ParaId,
+		blob: Vec<u8>,
+		meter: &mut WeightMeter,
+	) -> Result<(), ()>;
+}
+
+impl HandleBlobMessage for () {
+	fn handle_blob_message(_: ParaId, _: Vec<u8>, _: &mut WeightMeter) -> Result<(), ()> {
+		defensive!("Blob messages are unhandled - dropping");
+		Err(())
+	}
+}
+
+/// The reason why inbound XCMP messages were dropped.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum DropReason {
+	/// There was not enough weight left to process the messages.
+	OutOfWeight,
+	/// The messages could not be decoded.
+	DecodeError,
+}
+
 impl<T: Config> Pallet<T> {
 	/// Place a message `fragment` on the outgoing XCMP queue for `recipient`.
 	///
@@ -461,7 +924,7 @@ impl<T: Config> Pallet<T> {
 			T::ChannelInfo::get_channel_info(recipient).ok_or(MessageSendError::NoChannel)?;
 		// Max message size refers to aggregates, or pages. Not to individual fragments.
 		let max_message_size = channel_info.max_message_size as usize;
-		let format_size = format.encoded_size();
+		let format_size = Self::page_header_size(format);
 		// We check the encoded fragment length plus the format size against the max message size
 		// because the format is concatenated if a new page is needed.
 		let size_to_check = encoded_fragment
@@ -492,18 +955,23 @@ impl<T: Config> Pallet<T> {
 					recipient,
 					channel_details.last_index - 1,
 					|page| {
-						if XcmpMessageFormat::decode_with_depth_limit(
+						match XcmpMessageFormat::decode_with_depth_limit(
 							MAX_XCM_DECODE_DEPTH,
 							&mut &page[..],
-						) != Ok(format)
-						{
-							defensive!("Bad format in outbound queue; dropping message");
-							return None
+						) {
+							Ok(f) if f == format => (),
+							// The format changed since the page was created; start a new one.
+							Ok(_) => return None,
+							Err(_) => {
+								defensive!("Bad format in outbound queue; dropping message");
+								return None
+							},
 						}
 						if page.len() + encoded_fragment.len() > max_message_size {
 							return None
 						}
 						page.extend_from_slice(&encoded_fragment[..]);
+						Self::update_page_checksum(format, page);
 						Some(page.len())
 					},
 				)
@@ -518,7 +986,12 @@ impl<T: Config> Pallet<T> {
 			let page_index = channel_details.last_index;
 			channel_details.last_index += 1;
 			let mut new_page = format.encode();
+			if format == XcmpMessageFormat::ChecksummedVersionedXcm {
+				// Placeholder which is updated below.
+				new_page.extend_from_slice(&0u32.encode());
+			}
 			new_page.extend_from_slice(&encoded_fragment[..]);
+			Self::update_page_checksum(format, &mut new_page);
 			let last_page_size = new_page.len();
 			let number_of_pages = (channel_details.last_index - channel_details.first_index) as u32;
 			<OutboundXcmpMessages<T>>::insert(recipient, page_index, new_page);
@@ -532,7 +1005,8 @@ impl<T: Config> Pallet<T> {
 		let total_size =
 			number_of_pages.saturating_sub(1) * max_message_size as u32 + last_page_size as u32;
 		let threshold = channel_info.max_total_size / delivery_fee_constants::THRESHOLD_FACTOR;
-		if total_size > threshold {
+		// Priority channels are exempt from congestion fees.
+		if total_size > threshold && !T::PriorityDestinations::contains(&recipient) {
 			let message_size_factor = FixedU128::from((encoded_fragment.len() / 1024) as u128)
 				.saturating_mul(delivery_fee_constants::MESSAGE_SIZE_FEE_BASE);
 			Self::increase_fee_factor(recipient, message_size_factor);
@@ -541,19 +1015,56 @@ impl<T: Config> Pallet<T> {
 		Ok(number_of_pages)
 	}
 
-	/// Sends a signal to the `dest` chain over XCMP. This is guaranteed to be dispatched on this
-	/// block.
-	fn send_signal(dest: ParaId, signal: ChannelSignal) {
+	/// The size of the header of a page with the given `format`.
+	fn page_header_size(format: XcmpMessageFormat) -> usize {
+		match format {
+			XcmpMessageFormat::ChecksummedVersionedXcm =>
+				format.encoded_size().saturating_add(0u32.encoded_size()),
+			_ => format.encoded_size(),
+		}
+	}
+
+	/// Recompute the checksum of an outbound `page`, if its `format` carries one.
+	fn update_page_checksum(format: XcmpMessageFormat, page: &mut Vec<u8>) {
+		if format != XcmpMessageFormat::ChecksummedVersionedXcm {
+			return
+		}
+
+		let format_size = format.encoded_size();
+		let body_start = Self::page_header_size(format);
+		let checksum = page_checksum(&page[body_start..]);
+		page[format_size..body_start].copy_from_slice(&checksum.encode());
+	}
+
+	/// Queues a signal for the `dest` chain over XCMP, replacing any signal already queued for
+	/// `dest`. Queued signals are sent ahead of the messages of their channel.
+	///
+	/// Fails if `dest` has no signal queued yet and [`Config::MaxQueuedSignals`] channels already
+	/// have one.
+	fn send_signal(dest: ParaId, signal: ChannelSignal) -> Result<(), Error<T>> {
 		let mut s = <OutboundXcmpStatus<T>>::get();
-		if let Some(details) = s.iter_mut().find(|item| item.recipient == dest) {
-			details.signals_exist = true;
-		} else {
-			s.push(OutboundChannelDetails::new(dest).with_signals());
+		let queued = s.iter().filter(|item| item.signals_exist).count();
+		match s.iter_mut().find(|item| item.recipient == dest) {
+			Some(details) if details.signals_exist => {},
+			_ if queued >= T::MaxQueuedSignals::get() as usize => {
+				log::warn!(
+					target: LOG_TARGET,
+					"Too many signals queued; cannot send signal to sibling {:?}",
+					dest
+				);
+				Self::deposit_event(Event::SignalQueueFull { recipient: dest });
+				return Err(Error::<T>::TooManyQueuedSignals)
+			},
+			Some(details) => details.signals_exist = true,
+			None => s.push(OutboundChannelDetails::new(dest).with_signals()),
 		}
 		<SignalMessages<T>>::mutate(dest, |page| {
 			*page = (XcmpMessageFormat::Signals, signal).encode();
 		});
 		<OutboundXcmpStatus<T>>::put(s);
+
+		Self::deposit_event(Event::SignalSent { dest, signal });
+		Ok(())
 	}
 
 	fn suspend_channel(target: ParaId) {
@@ -587,17 +1098,133 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Handle a single inbound XCMP page from `sender`.
+	///
+	/// Returns the number of messages that were handed on for processing.
+	fn handle_xcmp_page(sender: ParaId, mut data: &[u8], meter: &mut WeightMeter) -> u32 {
+		// Every page starts with a single format header which applies to the whole page. Pages
+		// that attempt to switch to another format midway are dropped from that point on.
+		let format = match XcmpMessageFormat::decode(&mut data) {
+			Ok(f) => f,
+			Err(_) => {
+				defensive!("Unknown XCMP message format - dropping");
+				return 0
+			},
+		};
+
+		if format == XcmpMessageFormat::ChecksummedVersionedXcm {
+			let checksum_ok =
+				u32::decode(&mut data).map_or(false, |checksum| checksum == page_checksum(data));
+			if !checksum_ok {
+				log::warn!(
+					target: LOG_TARGET,
+					"Checksum mismatch in page from sibling {:?} - dropping",
+					sender
+				);
+				Self::deposit_event(Event::PageChecksumMismatch { sender });
+				return 0
+			}
+		}
+
+		let mut processed = 0u32;
+		match format {
+			XcmpMessageFormat::Signals => {
+				// Only charge for the signals that are actually processed, not for the
+				// remainder of a page that stops being decodable.
+				let signal_weight =
+					T::WeightInfo::suspend_channel().max(T::WeightInfo::resume_channel());
+				while !data.is_empty() {
+					if !meter.can_consume(signal_weight) {
+						defensive!("Not enough weight to process signals - dropping");
+						break
+					}
+
+					let remaining = data;
+					match ChannelSignal::decode(&mut data) {
+						Ok(ChannelSignal::Suspend) => Self::suspend_channel(sender),
+						Ok(ChannelSignal::Resume) => Self::resume_channel(sender),
+						Err(_) if Self::is_format_switch(format, remaining) => {
+							log::warn!(
+								target: LOG_TARGET,
+								"Page from sibling {:?} switched its format - dropping",
+								sender
+							);
+							Self::deposit_event(Event::MixedFormatPage { sender });
+							break
+						},
+						Err(_) => {
+							defensive!("Undecodable channel signal - dropping");
+							break
+						},
+					}
+					meter.consume(signal_weight);
+				}
+			},
+			XcmpMessageFormat::ConcatenatedVersionedXcm |
+			XcmpMessageFormat::ChecksummedVersionedXcm =>
+				while !data.is_empty() {
+					let remaining = data;
+					let Ok(xcm) = Self::take_first_concatenated_xcm(&mut data, meter) else {
+						let reason =
+							if meter.can_consume(T::WeightInfo::take_first_concatenated_xcm()) {
+								DropReason::DecodeError
+							} else {
+								DropReason::OutOfWeight
+							};
+						if reason == DropReason::DecodeError &&
+							Self::is_format_switch(format, remaining)
+						{
+							log::warn!(
+								target: LOG_TARGET,
+								"Page from sibling {:?} switched its format - dropping",
+								sender
+							);
+							Self::deposit_event(Event::MixedFormatPage { sender });
+							break
+						}
+						Self::note_dropped_messages(sender, 1, reason);
+						defensive!("HRMP inbound decode stream broke; page will be dropped.",);
+						break
+					};
+
+					if let Err(()) = Self::enqueue_xcmp_message(sender, xcm, meter) {
+						defensive!(
+							"Could not enqueue XCMP messages. Used weight: ",
+							meter.consumed_ratio()
+						);
+						break
+					}
+					processed.saturating_inc();
+				},
+			XcmpMessageFormat::ConcatenatedEncodedBlob =>
+				while !data.is_empty() {
+					let Ok(blob) = Self::take_first_concatenated_blob(&mut data, meter) else {
+						defensive!("HRMP inbound blob stream broke; page will be dropped.");
+						break
+					};
+
+					if T::BlobHandler::handle_blob_message(sender, blob, meter).is_err() {
+						break
+					}
+					processed.saturating_inc();
+				},
+		}
+
+		processed
+	}
+
 	fn enqueue_xcmp_message(
 		sender: ParaId,
 		xcm: BoundedVec<u8, MaxXcmpMessageLenOf<T>>,
 		meter: &mut WeightMeter,
 	) -> Result<(), ()> {
 		if meter.try_consume(T::WeightInfo::enqueue_xcmp_message()).is_err() {
+			Self::note_dropped_messages(sender, 1, DropReason::OutOfWeight);
 			defensive!("Out of weight: cannot enqueue XCMP messages; dropping msg");
 			return Err(())
 		}
 
-		let QueueConfigData { drop_threshold, .. } = <QueueConfig<T>>::get();
+		let QueueConfigData { drop_threshold, .. } = Self::channel_queue_config(sender);
 		let fp = T::XcmpQueue::footprint(sender);
 		// Assume that it will not fit into the current page:
 		let new_pages = fp.ready_pages.saturating_add(1);
@@ -629,20 +1256,144 @@ impl<T: Config> Pallet<T> {
 			return Err(())
 		}
 
-		let xcm = VersionedXcm::<()>::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, data)
+		let xcm = VersionedXcm::<()>::decode_with_depth_limit(T::MaxXcmDecodeDepth::get(), data)
 			.map_err(|_| ())?;
 		xcm.encode().try_into().map_err(|_| ())
 	}
 
+	/// Split the `body` of an outbound page with the given `format` into its encoded fragments.
+	fn split_page_fragments(
+		format: XcmpMessageFormat,
+		mut body: &[u8],
+	) -> Result<Vec<&[u8]>, Error<T>> {
+		let mut fragments = Vec::new();
+		while !body.is_empty() {
+			let remaining = body;
+			let decoded = match format {
+				XcmpMessageFormat::ConcatenatedVersionedXcm |
+				XcmpMessageFormat::ChecksummedVersionedXcm =>
+					VersionedXcm::<()>::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut body)
+						.map(|_| ()),
+				XcmpMessageFormat::ConcatenatedEncodedBlob =>
+					<Vec<u8>>::decode(&mut body).map(|_| ()),
+				// Signals are never queued as outbound pages.
+				XcmpMessageFormat::Signals => return Err(Error::<T>::BadOutboundPage),
+			};
+			decoded.map_err(|_| Error::<T>::BadOutboundPage)?;
+			fragments.push(&remaining[..remaining.len() - body.len()]);
+		}
+		Ok(fragments)
+	}
+
+	/// The queue configuration of the channel with `para`.
+	///
+	/// Falls back to the global [`QueueConfig`] if there is no override for the channel.
+	pub fn channel_queue_config(para: ParaId) -> QueueConfigData {
+		<ChannelQueueConfig<T>>::get(para).unwrap_or_else(<QueueConfig<T>>::get)
+	}
+
+	/// The recipient, state and number of queued pages of every tracked outbound channel.
+	///
+	/// Used by the `XcmpQueueApi` runtime API.
+	pub fn outbound_channel_states() -> Vec<(ParaId, OutboundState, u16)> {
+		<OutboundXcmpStatus<T>>::get()
+			.into_iter()
+			.map(|c| (c.recipient, c.state, c.last_index.saturating_sub(c.first_index)))
+			.collect()
+	}
+
+	/// SCALE-encode the whole outbound state into an [`OutboundStateSnapshot`].
+	///
+	/// The state can be restored with [`Pallet::import_outbound_state`]. Iterates over all
+	/// queued pages, hence should only be used off-chain.
+	pub fn export_outbound_state() -> Vec<u8> {
+		OutboundStateSnapshot {
+			channels: <OutboundXcmpStatus<T>>::get(),
+			pages: <OutboundXcmpMessages<T>>::iter().collect(),
+			signals: <SignalMessages<T>>::iter().collect(),
+		}
+		.encode()
+	}
+
+	/// The number of pages currently queued for delivery to the sibling `recipient`.
+	pub fn outbound_queued_pages(recipient: ParaId) -> u16 {
+		<OutboundXcmpStatus<T>>::get()
+			.iter()
+			.find(|c| c.recipient == recipient)
+			.map_or(0, |c| c.last_index.saturating_sub(c.first_index))
+	}
+
+	/// The number of inbound messages from `para` that were dropped since they ran out of weight
+	/// or could not be decoded.
+	pub fn dropped_message_count(para: ParaId) -> u32 {
+		<DroppedMessageCount<T>>::get(para)
+	}
+
+	/// Count `count` inbound messages from `sender` as dropped and report it with an event.
+	fn note_dropped_messages(sender: ParaId, count: u32, reason: DropReason) {
+		<DroppedMessageCount<T>>::mutate(sender, |dropped| {
+			*dropped = dropped.saturating_add(count);
+		});
+		Self::deposit_event(Event::InboundXcmpMessagesDropped { sender, count, reason });
+	}
+
+	/// Whether `data` starts with an [`XcmpMessageFormat`] header other than `format`, followed by
+	/// a valid item of that format.
+	///
+	/// A page declares its format exactly once, in its first byte, and the remainder of the page
+	/// must only consist of items of that format. Used to tell a page that attempts to switch
+	/// its format apart from other decoding errors, once an item failed to decode. The header
+	/// alone is not enough, since the version byte of an undecodable [`VersionedXcm`] looks like
+	/// a format header as well.
+	fn is_format_switch(format: XcmpMessageFormat, mut data: &[u8]) -> bool {
+		let Ok(switched) = XcmpMessageFormat::decode(&mut data) else { return false };
+		let decodes_as_xcm = |data: &mut &[u8]| {
+			VersionedXcm::<()>::decode_with_depth_limit(T::MaxXcmDecodeDepth::get(), data).is_ok()
+		};
+
+		switched != format &&
+			match switched {
+				XcmpMessageFormat::Signals => ChannelSignal::decode(&mut data).is_ok(),
+				XcmpMessageFormat::ConcatenatedEncodedBlob => Vec::<u8>::decode(&mut data).is_ok(),
+				XcmpMessageFormat::ConcatenatedVersionedXcm => decodes_as_xcm(&mut data),
+				XcmpMessageFormat::ChecksummedVersionedXcm =>
+					u32::decode(&mut data).is_ok() && decodes_as_xcm(&mut data),
+			}
+	}
+
+	/// Split off the first blob of concatenated encoded `Vec<u8>` blobs.
+	pub(crate) fn take_first_concatenated_blob(
+		data: &mut &[u8],
+		meter: &mut WeightMeter,
+	) -> Result<Vec<u8>, ()> {
+		if data.is_empty() {
+			return Err(())
+		}
+
+		if meter.try_consume(T::WeightInfo::process_blob()).is_err() {
+			defensive!("Out of weight; could not decode all; dropping");
+			return Err(())
+		}
+
+		Vec::<u8>::decode(data).map_err(|_| ())
+	}
+
 	/// The worst-case weight of `on_idle`.
 	pub fn on_idle_weight() -> Weight {
 		<T as crate::Config>::WeightInfo::on_idle_good_msg()
 			.max(<T as crate::Config>::WeightInfo::on_idle_large_msg())
 	}
 
+	/// Whether the inbound channel from `sender` is currently suspended.
+	///
+	/// Used by the `XcmpQueueApi` runtime API.
+	pub fn is_inbound_suspended(sender: ParaId) -> bool {
+		<InboundXcmpSuspended<T>>::get().contains(&sender)
+	}
+
 	#[cfg(feature = "bridging")]
 	fn is_inbound_channel_suspended(sender: ParaId) -> bool {
-		<InboundXcmpSuspended<T>>::get().iter().any(|c| c == &sender)
+		Self::is_inbound_suspended(sender)
 	}
 
 	#[cfg(feature = "bridging")]
@@ -655,22 +1406,43 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+/// The CRC32 (IEEE 802.3) checksum of `data`, as used by
+/// [`XcmpMessageFormat::ChecksummedVersionedXcm`] pages.
+pub fn page_checksum(data: &[u8]) -> u32 {
+	let mut crc = !0u32;
+	for byte in data {
+		crc ^= *byte as u32;
+		for _ in 0..8 {
+			let mask = (crc & 1).wrapping_neg();
+			crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+		}
+	}
+	!crc
+}
+
 impl<T: Config> OnQueueChanged<ParaId> for Pallet<T> {
 	// Suspends/Resumes the queue when certain thresholds are reached.
 	fn on_queue_changed(para: ParaId, fp: QueueFootprint) {
-		let QueueConfigData { resume_threshold, suspend_threshold, .. } = <QueueConfig<T>>::get();
+		let QueueConfigData { resume_threshold, suspend_threshold, .. } =
+			Self::channel_queue_config(para);
 
 		let mut suspended_channels = <InboundXcmpSuspended<T>>::get();
 		let suspended = suspended_channels.contains(&para);
 
+		// If the signal cannot be queued, the channel keeps its state and the signal is retried on
+		// the next change of the queue.
 		if suspended && fp.ready_pages <= resume_threshold {
-			Self::send_signal(para, ChannelSignal::Resume);
+			if Self::send_signal(para, ChannelSignal::Resume).is_err() {
+				return
+			}
 
 			suspended_channels.remove(&para);
 			<InboundXcmpSuspended<T>>::put(suspended_channels);
 		} else if !suspended && fp.ready_pages >= suspend_threshold {
 			log::warn!("XCMP queue for sibling {:?} is full; suspending channel.", para);
-			Self::send_signal(para, ChannelSignal::Suspend);
+			if Self::send_signal(para, ChannelSignal::Suspend).is_err() {
+				return
+			}
 
 			if let Err(err) = suspended_channels.try_insert(para) {
 				log::error!("Too many channels suspended; cannot suspend sibling {:?}: {:?}; further messages may be dropped.", para, err);
@@ -705,59 +1477,23 @@ impl<T: Config> XcmpMessageHandler for Pallet<T> {
 	) -> Weight {
 		let mut meter = WeightMeter::with_limit(max_weight);
 
-		for (sender, _sent_at, mut data) in iter {
-			let format = match XcmpMessageFormat::decode(&mut data) {
-				Ok(f) => f,
-				Err(_) => {
-					defensive!("Unknown XCMP message format - dropping");
-					continue
-				},
-			};
-
-			match format {
-				XcmpMessageFormat::Signals =>
-					while !data.is_empty() {
-						if meter
-							.try_consume(
-								T::WeightInfo::suspend_channel()
-									.max(T::WeightInfo::resume_channel()),
-							)
-							.is_err()
-						{
-							defensive!("Not enough weight to process signals - dropping");
-							break
-						}
-
-						match ChannelSignal::decode(&mut data) {
-							Ok(ChannelSignal::Suspend) => Self::suspend_channel(sender),
-							Ok(ChannelSignal::Resume) => Self::resume_channel(sender),
-							Err(_) => {
-								defensive!("Undecodable channel signal - dropping");
-								break
-							},
-						}
-					},
-				XcmpMessageFormat::ConcatenatedVersionedXcm =>
-					while !data.is_empty() {
-						let Ok(xcm) = Self::take_first_concatenated_xcm(&mut data, &mut meter)
-						else {
-							defensive!("HRMP inbound decode stream broke; page will be dropped.",);
-							break
-						};
+		// Once a sender has had `MaxMessagesPerSenderPerBlock` messages processed, its remaining
+		// pages are put aside until every other sender had its turn.
+		let max_per_sender = T::MaxMessagesPerSenderPerBlock::get();
+		let mut processed_per_sender = BTreeMap::<ParaId, u32>::new();
+		let mut deferred = Vec::new();
 
-						if let Err(()) = Self::enqueue_xcmp_message(sender, xcm, &mut meter) {
-							defensive!(
-								"Could not enqueue XCMP messages. Used weight: ",
-								meter.consumed_ratio()
-							);
-							break
-						}
-					},
-				XcmpMessageFormat::ConcatenatedEncodedBlob => {
-					defensive!("Blob messages are unhandled - dropping");
-					continue
-				},
+		for (sender, _sent_at, data) in iter {
+			let processed = processed_per_sender.entry(sender).or_default();
+			if *processed >= max_per_sender {
+				deferred.push((sender, data));
+				continue
 			}
+			processed.saturating_accrue(Self::handle_xcmp_page(sender, data, &mut meter));
+		}
+
+		for (sender, data) in deferred {
+			Self::handle_xcmp_page(sender, data, &mut meter);
 		}
 
 		meter.consumed()
@@ -771,6 +1507,10 @@ impl<T: Config> XcmpMessageSource for Pallet<T> {
 		let max_message_count = statuses.len().min(maximum_channels);
 		let mut result = Vec::with_capacity(max_message_count);
 
+		// Service the priority channels first. The sort is stable, so the fair order of the other
+		// channels is kept.
+		statuses.sort_by_key(|status| !T::PriorityDestinations::contains(&status.recipient));
+
 		for status in statuses.iter_mut() {
 			let OutboundChannelDetails {
 				recipient: para_id,
@@ -934,9 +1674,19 @@ impl<T: Config> SendXcm for Pallet<T> {
 	fn deliver((id, xcm): (ParaId, VersionedXcm<()>)) -> Result<XcmHash, SendError> {
 		let hash = xcm.using_encoded(sp_io::hashing::blake2_256);
 
-		match Self::send_fragment(id, XcmpMessageFormat::ConcatenatedVersionedXcm, xcm) {
+		let format = if T::EmitPageChecksums::get() {
+			XcmpMessageFormat::ChecksummedVersionedXcm
+		} else {
+			XcmpMessageFormat::ConcatenatedVersionedXcm
+		};
+
+		match Self::send_fragment(id, format, xcm) {
 			Ok(_) => {
 				Self::deposit_event(Event::XcmpMessageSent { message_hash: hash });
+				if T::EmitSentAt::get() {
+					let sent_at = T::RelayBlockNumberProvider::current_block_number();
+					Self::deposit_event(Event::XcmpMessageSentAt { message_hash: hash, sent_at });
+				}
 				Ok(hash)
 			},
 			Err(e) => {
@@ -956,9 +1706,9 @@ impl<T: Config> FeeTracker for Pallet<T> {
 
 	fn increase_fee_factor(id: Self::Id, message_size_factor: FixedU128) -> FixedU128 {
 		<DeliveryFeeFactor<T>>::mutate(id, |f| {
-			*f = f.saturating_mul(
-				delivery_fee_constants::EXPONENTIAL_FEE_BASE.saturating_add(message_size_factor),
-			);
+			let multiplier =
+				delivery_fee_constants::EXPONENTIAL_FEE_BASE.saturating_add(message_size_factor);
+			*f = f.saturating_mul(multiplier).min(T::MaxDeliveryFeeFactor::get());
 			*f
 		})
 	}
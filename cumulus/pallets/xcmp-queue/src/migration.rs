@@ -25,7 +25,7 @@ use frame_support::{
 };
 
 /// The in-code storage version.
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 pub const LOG: &str = "runtime::xcmp-queue-migration";
 
@@ -311,6 +311,33 @@ pub mod v4 {
 	>;
 }
 
+pub mod v5 {
+	use super::*;
+
+	/// Introduces [`DroppedMessageCount`](crate::DroppedMessageCount).
+	///
+	/// The map has no entries before this version and counters of channels without an entry read
+	/// as zero, hence there is nothing to migrate besides the storage version.
+	pub struct UncheckedMigrationToV5<T: Config>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrationToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			Weight::zero()
+		}
+	}
+
+	/// [`UncheckedMigrationToV5`] wrapped in a
+	/// [`VersionedMigration`](frame_support::migrations::VersionedMigration), ensuring the
+	/// migration is only performed when on-chain version is 4.
+	pub type MigrationToV5<T> = frame_support::migrations::VersionedMigration<
+		4,
+		5,
+		UncheckedMigrationToV5<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
 #[cfg(all(feature = "try-runtime", test))]
 mod tests {
 	use super::*;
@@ -419,4 +446,17 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn test_migration_to_v5() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(4).put::<Pallet<Test>>();
+
+			v5::MigrationToV5::<Test>::on_runtime_upgrade();
+
+			let sender = cumulus_primitives_core::ParaId::from(1000);
+			assert_eq!(Pallet::<Test>::dropped_message_count(sender), 0);
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 5);
+		});
+	}
 }
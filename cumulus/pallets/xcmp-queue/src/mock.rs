@@ -20,7 +20,7 @@ use cumulus_pallet_parachain_system::AnyRelayNumber;
 use cumulus_primitives_core::{ChannelInfo, IsSystem, ParaId};
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstU32, Everything, Nothing, OriginTrait},
+	traits::{ConstU32, Contains, Everything, Nothing, OriginTrait},
 	BoundedSlice,
 };
 use frame_system::EnsureRoot;
@@ -271,16 +271,72 @@ pub type PriceForSiblingParachainDelivery = polkadot_runtime_common::xcm_sender:
 	XcmpQueue,
 >;
 
+parameter_types! {
+	pub static EmitPageChecksums: bool = false;
+	pub static MaxQueuedSignals: u32 = 1_000;
+	pub static MaxMessagesPerSenderPerBlock: u32 = u32::MAX;
+	pub static MaxXcmDecodeDepth: u32 = MAX_XCM_DECODE_DEPTH;
+	pub static MaxDeliveryFeeFactor: FixedU128 = DefaultMaxDeliveryFeeFactor::get();
+	pub static EmitSentAt: bool = false;
+	pub static MockRelayBlockNumber: RelayBlockNumber = 0;
+	pub static PriorityDestinations: Vec<ParaId> = vec![];
+}
+
+parameter_types! {
+	/// The blobs handled by [`RecordBlobs`], or `None` to handle them like `()` does.
+	pub static RecordedBlobs: Option<Vec<(ParaId, Vec<u8>)>> = None;
+}
+
+impl BlockNumberProvider for MockRelayBlockNumber {
+	type BlockNumber = RelayBlockNumber;
+
+	fn current_block_number() -> RelayBlockNumber {
+		Self::get()
+	}
+}
+
+impl Contains<ParaId> for PriorityDestinations {
+	fn contains(para: &ParaId) -> bool {
+		Self::get().contains(para)
+	}
+}
+
+/// A `HandleBlobMessage` implementation that records the blobs in thread-local storage.
+pub struct RecordBlobs;
+impl HandleBlobMessage for RecordBlobs {
+	fn handle_blob_message(
+		sender: ParaId,
+		blob: Vec<u8>,
+		meter: &mut WeightMeter,
+	) -> Result<(), ()> {
+		let Some(mut blobs) = RecordedBlobs::get() else {
+			return <() as HandleBlobMessage>::handle_blob_message(sender, blob, meter)
+		};
+		blobs.push((sender, blob));
+		RecordedBlobs::set(Some(blobs));
+		Ok(())
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ChannelInfo = MockedChannelInfo;
 	type VersionWrapper = ();
 	type XcmpQueue = EnqueueToLocalStorage<Pallet<Test>>;
 	type MaxInboundSuspended = sp_core::ConstU32<1_000>;
+	type MaxMessagesPerSenderPerBlock = MaxMessagesPerSenderPerBlock;
+	type MaxXcmDecodeDepth = MaxXcmDecodeDepth;
 	type ControllerOrigin = EnsureRoot<AccountId>;
 	type ControllerOriginConverter = SystemParachainAsSuperuser<RuntimeOrigin>;
 	type WeightInfo = ();
 	type PriceForSiblingDelivery = PriceForSiblingParachainDelivery;
+	type EmitPageChecksums = EmitPageChecksums;
+	type BlobHandler = RecordBlobs;
+	type MaxQueuedSignals = MaxQueuedSignals;
+	type MaxDeliveryFeeFactor = MaxDeliveryFeeFactor;
+	type PriorityDestinations = PriorityDestinations;
+	type EmitSentAt = EmitSentAt;
+	type RelayBlockNumberProvider = MockRelayBlockNumber;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -98,6 +98,53 @@ fn xcm_enqueueing_multiple_times_works() {
 	})
 }
 
+#[test]
+fn xcm_enqueueing_defers_senders_over_the_per_block_limit() {
+	new_test_ext().execute_with(|| {
+		mock::MaxMessagesPerSenderPerBlock::set(2);
+
+		let xcm =
+			|i: usize| VersionedXcm::<Test>::from(Xcm::<Test>(vec![ClearOrigin; i])).encode();
+		let page = |xcms: &[usize]| {
+			let mut data = ConcatenatedVersionedXcm.encode();
+			xcms.iter().for_each(|i| data.extend(xcm(*i)));
+			data
+		};
+		let (a1, a2, a3) = (page(&[1, 2]), page(&[3]), page(&[4]));
+		let b1 = page(&[5, 6]);
+
+		XcmpQueue::handle_xcmp_messages(
+			vec![
+				(1000.into(), 1, a1.as_slice()),
+				(1000.into(), 1, a2.as_slice()),
+				(1000.into(), 1, a3.as_slice()),
+				(2000.into(), 1, b1.as_slice()),
+			]
+			.into_iter(),
+			Weight::MAX,
+		);
+
+		// The second sender is handled before the deferred pages of the first one, which keep
+		// their order.
+		assert_eq!(
+			EnqueuedMessages::get(),
+			vec![
+				(1000.into(), xcm(1)),
+				(1000.into(), xcm(2)),
+				(2000.into(), xcm(5)),
+				(2000.into(), xcm(6)),
+				(1000.into(), xcm(3)),
+				(1000.into(), xcm(4)),
+			]
+		);
+
+		// The limit only applies within a single call.
+		EnqueuedMessages::take();
+		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, a1.as_slice())), Weight::MAX);
+		assert_eq!(EnqueuedMessages::get(), vec![(1000.into(), xcm(1)), (1000.into(), xcm(2))]);
+	})
+}
+
 #[test]
 #[cfg_attr(debug_assertions, should_panic = "Could not enqueue XCMP messages.")]
 fn xcm_enqueueing_starts_dropping_on_overflow() {
@@ -194,6 +241,24 @@ fn bad_blob_message_no_panic() {
 	});
 }
 
+/// Message blobs are passed to the `BlobHandler` one by one.
+#[test]
+fn blob_messages_are_handled() {
+	new_test_ext().execute_with(|| {
+		mock::RecordedBlobs::set(Some(vec![]));
+		let data =
+			[ConcatenatedEncodedBlob.encode(), vec![1u8].encode(), vec![2u8, 3].encode()].concat();
+
+		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
+
+		assert_eq!(
+			mock::RecordedBlobs::get(),
+			Some(vec![(1000.into(), vec![1]), (1000.into(), vec![2, 3])])
+		);
+		assert!(EnqueuedMessages::get().is_empty());
+	});
+}
+
 /// Invalid concatenated XCMs panic in debug mode.
 #[test]
 #[should_panic = "HRMP inbound decode stream broke; page will be dropped."]
@@ -220,6 +285,177 @@ fn handle_invalid_data_no_panic() {
 	});
 }
 
+/// Dropped inbound XCMs are reported with the reason of dropping them.
+#[test]
+#[cfg(not(debug_assertions))]
+fn dropped_inbound_xcms_emit_event() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+
+		let data = [ConcatenatedVersionedXcm.encode(), Xcm::<Test>(vec![]).encode()].concat();
+		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::InboundXcmpMessagesDropped {
+				sender: 1000.into(),
+				count: 1,
+				reason: DropReason::DecodeError,
+			}
+			.into(),
+		);
+
+		let xcm = VersionedXcm::<Test>::from(Xcm::<Test>(vec![ClearOrigin]));
+		let data = (ConcatenatedVersionedXcm, xcm).encode();
+		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::zero());
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::InboundXcmpMessagesDropped {
+				sender: 1000.into(),
+				count: 1,
+				reason: DropReason::OutOfWeight,
+			}
+			.into(),
+		);
+		assert!(EnqueuedMessages::get().is_empty());
+	});
+}
+
+/// Dropped inbound XCMs are counted per sender.
+#[test]
+#[cfg(not(debug_assertions))]
+fn dropped_inbound_xcms_are_counted() {
+	new_test_ext().execute_with(|| {
+		let xcm = VersionedXcm::<Test>::from(Xcm::<Test>(vec![ClearOrigin]));
+		let data = (ConcatenatedVersionedXcm, xcm).encode();
+		assert_eq!(XcmpQueue::dropped_message_count(1000.into()), 0);
+
+		for dropped in 1..=3 {
+			XcmpQueue::handle_xcmp_messages(
+				once((1000.into(), 1, data.as_slice())),
+				Weight::zero(),
+			);
+			assert_eq!(XcmpQueue::dropped_message_count(1000.into()), dropped);
+		}
+		// Other senders are unaffected.
+		assert_eq!(XcmpQueue::dropped_message_count(2000.into()), 0);
+		assert!(EnqueuedMessages::get().is_empty());
+	});
+}
+
+/// Inbound XCMs nested deeper than `MaxXcmDecodeDepth` are dropped as undecodable.
+#[test]
+#[cfg(not(debug_assertions))]
+fn xcm_nested_beyond_configured_depth_is_dropped() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		mock::MaxXcmDecodeDepth::set(2);
+		let good = Xcm::<Test>(vec![SetAppendix(Xcm(vec![ClearOrigin]))]);
+		let bad = Xcm::<Test>(vec![SetAppendix(good.clone())]);
+		let good = VersionedXcm::<Test>::from(good);
+
+		let data =
+			(ConcatenatedVersionedXcm, good.clone(), VersionedXcm::<Test>::from(bad)).encode();
+		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
+
+		assert_eq!(EnqueuedMessages::get(), vec![(1000.into(), good.encode())]);
+		assert_eq!(XcmpQueue::dropped_message_count(1000.into()), 1);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::InboundXcmpMessagesDropped {
+				sender: 1000.into(),
+				count: 1,
+				reason: DropReason::DecodeError,
+			}
+			.into(),
+		);
+	});
+}
+
+/// Pages must not switch their format midway.
+#[test]
+fn mixed_format_page_is_rejected() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		let xcm = VersionedXcm::<Test>::from(Xcm::<Test>(vec![ClearOrigin])).encode();
+
+		// A page of XCMs which continues with blobs.
+		let data = [
+			ConcatenatedVersionedXcm.encode(),
+			xcm.clone(),
+			ConcatenatedEncodedBlob.encode(),
+			vec![1u8].encode(),
+		]
+		.concat();
+		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
+
+		// Only the XCM before the switch is enqueued.
+		assert_eq!(EnqueuedMessages::get(), vec![(1000.into(), xcm.clone())]);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::MixedFormatPage { sender: 1000.into() }.into(),
+		);
+
+		// A page of signals which continues with XCMs.
+		let data = [
+			(Signals, ChannelSignal::Suspend).encode(),
+			ChecksummedVersionedXcm.encode(),
+			page_checksum(&xcm).encode(),
+			xcm,
+		]
+		.concat();
+		XcmpQueue::handle_xcmp_messages(once((2000.into(), 1, data.as_slice())), Weight::MAX);
+
+		assert_eq!(EnqueuedMessages::get().len(), 1);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::MixedFormatPage { sender: 2000.into() }.into(),
+		);
+	});
+}
+
+/// An undecodable XCM whose version byte happens to be a format header is not a format switch.
+#[test]
+#[cfg(not(debug_assertions))]
+fn undecodable_xcm_is_not_a_format_switch() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		let xcm = VersionedXcm::<Test>::from(Xcm::<Test>(vec![ClearOrigin])).encode();
+		// A truncated V2 XCM, whose leading `2` is also the header of `Signals`.
+		let mut truncated = v2_xcm().encode();
+		truncated.pop();
+		assert_eq!(truncated[0], Signals.encode()[0]);
+
+		let data = [ConcatenatedVersionedXcm.encode(), xcm.clone(), truncated].concat();
+		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
+
+		assert_eq!(EnqueuedMessages::get(), vec![(1000.into(), xcm)]);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::InboundXcmpMessagesDropped {
+				sender: 1000.into(),
+				count: 1,
+				reason: DropReason::DecodeError,
+			}
+			.into(),
+		);
+	});
+}
+
+/// Signals are only charged for once they are processed.
+#[test]
+fn signal_page_weight_matches_processed_signals() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		let signal_weight = <Test as Config>::WeightInfo::suspend_channel()
+			.max(<Test as Config>::WeightInfo::resume_channel());
+
+		// A valid signal, followed by a part of the page that cannot be processed.
+		let data = [(Signals, ChannelSignal::Suspend).encode(), ChecksummedVersionedXcm.encode()]
+			.concat();
+		let consumed =
+			XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
+
+		assert_eq!(consumed, signal_weight);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::MixedFormatPage { sender: 1000.into() }.into(),
+		);
+	});
+}
+
 #[test]
 fn suspend_xcm_execution_works() {
 	new_test_ext().execute_with(|| {
@@ -287,6 +523,18 @@ fn xcm_enqueueing_backpressure_works() {
 	});
 }
 
+#[test]
+fn is_inbound_suspended_works() {
+	new_test_ext().execute_with(|| {
+		let para = ParaId::from(1000);
+		assert!(!XcmpQueue::is_inbound_suspended(para));
+
+		InboundXcmpSuspended::<Test>::mutate(|s| s.try_insert(para).unwrap());
+		assert!(XcmpQueue::is_inbound_suspended(para));
+		assert!(!XcmpQueue::is_inbound_suspended(ParaId::from(2000)));
+	});
+}
+
 #[test]
 fn update_suspend_threshold_works() {
 	new_test_ext().execute_with(|| {
@@ -328,6 +576,143 @@ fn update_resume_threshold_works() {
 	});
 }
 
+#[test]
+fn set_channel_config_works() {
+	new_test_ext().execute_with(|| {
+		let para: ParaId = 1000.into();
+		let config =
+			QueueConfigData { suspend_threshold: 2, drop_threshold: 3, resume_threshold: 1 };
+
+		assert_noop!(XcmpQueue::set_channel_config(Origin::signed(2), para, config), BadOrigin);
+		assert_noop!(
+			XcmpQueue::set_channel_config(
+				Origin::root(),
+				para,
+				QueueConfigData { resume_threshold: 0, ..config }
+			),
+			Error::<Test>::BadQueueConfig
+		);
+		assert_ok!(XcmpQueue::set_channel_config(Origin::root(), para, config));
+
+		// Only the configured channel is affected:
+		assert_eq!(XcmpQueue::channel_queue_config(para), config);
+		assert_eq!(XcmpQueue::channel_queue_config(2000.into()), QueueConfig::<Test>::get());
+
+		// The override thresholds are used to suspend and resume the channel.
+		let footprint = |ready_pages| QueueFootprint { ready_pages, ..Default::default() };
+		XcmpQueue::on_queue_changed(2000.into(), footprint(2));
+		XcmpQueue::on_queue_changed(para, footprint(2));
+		assert_eq!(InboundXcmpSuspended::<Test>::get().into_iter().collect::<Vec<_>>(), vec![para]);
+
+		XcmpQueue::on_queue_changed(para, footprint(1));
+		assert!(InboundXcmpSuspended::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn force_resume_inbound_channel_works() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		let para: ParaId = 1000.into();
+		InboundXcmpSuspended::<Test>::mutate(|s| s.try_insert(para).unwrap());
+
+		assert_noop!(XcmpQueue::force_resume_inbound_channel(Origin::signed(2), para), BadOrigin);
+		assert_ok!(XcmpQueue::force_resume_inbound_channel(Origin::root(), para));
+
+		assert!(InboundXcmpSuspended::<Test>::get().is_empty());
+		assert_eq!(
+			SignalMessages::<Test>::get(para),
+			(XcmpMessageFormat::Signals, ChannelSignal::Resume).encode()
+		);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::InboundChannelForceResumed { sender: para }.into(),
+		);
+	});
+}
+
+#[test]
+fn xcmp_message_sent_at_is_emitted_if_enabled() {
+	let sibling_para_id = ParaId::from(12345);
+	let dest: Location = (Parent, Parachain(sibling_para_id.into())).into();
+
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(sibling_para_id);
+		mock::MockRelayBlockNumber::set(42);
+
+		let message_hash = send_xcm::<XcmpQueue>(dest.clone(), Xcm(vec![ClearOrigin])).unwrap().0;
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::XcmpMessageSent { message_hash }.into(),
+		);
+
+		mock::EmitSentAt::set(true);
+		let message_hash = send_xcm::<XcmpQueue>(dest, Xcm(vec![ClearOrigin])).unwrap().0;
+		frame_system::Pallet::<Test>::assert_has_event(
+			Event::<Test>::XcmpMessageSent { message_hash }.into(),
+		);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::XcmpMessageSentAt { message_hash, sent_at: 42 }.into(),
+		);
+	});
+}
+
+#[test]
+fn send_signal_emits_event() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		let para: ParaId = 1000.into();
+
+		assert_ok!(XcmpQueue::send_signal(para, ChannelSignal::Suspend));
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::SignalSent { dest: para, signal: ChannelSignal::Suspend }.into(),
+		);
+
+		// Signals sent on queue changes are visible as well.
+		InboundXcmpSuspended::<Test>::mutate(|s| s.try_insert(para).unwrap());
+		XcmpQueue::on_queue_changed(para, QueueFootprint::default());
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::SignalSent { dest: para, signal: ChannelSignal::Resume }.into(),
+		);
+	});
+}
+
+#[test]
+fn queued_signals_are_capped() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		mock::MaxQueuedSignals::set(2);
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(1000.into());
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(1001.into());
+
+		assert_ok!(XcmpQueue::send_signal(1000.into(), ChannelSignal::Suspend));
+		assert_ok!(XcmpQueue::send_signal(1001.into(), ChannelSignal::Suspend));
+		// Replacing a queued signal does not need a new slot.
+		assert_ok!(XcmpQueue::send_signal(1000.into(), ChannelSignal::Resume));
+
+		assert_err!(
+			XcmpQueue::send_signal(1002.into(), ChannelSignal::Suspend),
+			Error::<Test>::TooManyQueuedSignals
+		);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::SignalQueueFull { recipient: 1002.into() }.into(),
+		);
+		assert!(!SignalMessages::<Test>::contains_key(ParaId::from(1002)));
+
+		// A forced resume fails and keeps the channel suspended if the signal cannot be queued.
+		InboundXcmpSuspended::<Test>::mutate(|s| s.try_insert(1002.into()).unwrap());
+		assert_noop!(
+			XcmpQueue::force_resume_inbound_channel(Origin::root(), 1002.into()),
+			Error::<Test>::TooManyQueuedSignals
+		);
+		assert!(InboundXcmpSuspended::<Test>::get().contains(&ParaId::from(1002)));
+
+		// Delivering the queued signals frees their slots.
+		let taken = XcmpQueue::take_outbound_messages(usize::MAX);
+		assert_eq!(taken.len(), 2);
+		assert_ok!(XcmpQueue::send_signal(1002.into(), ChannelSignal::Suspend));
+	});
+}
+
 /// Validates [`validate`] for required Some(destination) and Some(message)
 struct OkFixedXcmHashWithAssertingRequiredInputsSender;
 impl OkFixedXcmHashWithAssertingRequiredInputsSender {
@@ -520,7 +905,7 @@ fn hrmp_signals_are_prioritized() {
 		});
 
 		// But a signal gets prioritized instead of the messages:
-		XcmpQueue::send_signal(sibling_para_id.into(), ChannelSignal::Suspend);
+		assert_ok!(XcmpQueue::send_signal(sibling_para_id.into(), ChannelSignal::Suspend));
 
 		let taken = XcmpQueue::take_outbound_messages(130);
 		assert_eq!(
@@ -844,3 +1229,453 @@ fn verify_fee_factor_increase_and_decrease() {
 		assert!(DeliveryFeeFactor::<Test>::get(sibling_para_id) < FixedU128::from_float(1.63));
 	});
 }
+
+#[test]
+fn fee_factor_is_capped() {
+	use cumulus_primitives_core::AbridgedHrmpChannel;
+	use sp_runtime::FixedU128;
+
+	let sibling_para_id = ParaId::from(12345);
+	let destination: Location = (Parent, Parachain(sibling_para_id.into())).into();
+	let xcm = Xcm(vec![ClearOrigin; 30]);
+
+	new_test_ext().execute_with(|| {
+		let max = FixedU128::from_u32(2);
+		mock::MaxDeliveryFeeFactor::set(max);
+
+		ParachainSystem::open_custom_outbound_hrmp_channel_for_benchmarks_or_tests(
+			sibling_para_id,
+			AbridgedHrmpChannel {
+				max_capacity: 10,
+				max_total_size: 1000,
+				max_message_size: 104,
+				msg_count: 0,
+				total_size: 0,
+				mqc_head: None,
+			},
+		);
+
+		// Congest the channel far beyond the point where the factor would exceed the maximum.
+		for _ in 0..100 {
+			assert_ok!(send_xcm::<XcmpQueue>(destination.clone(), xcm.clone()));
+			assert!(DeliveryFeeFactor::<Test>::get(sibling_para_id) <= max);
+		}
+		assert_eq!(DeliveryFeeFactor::<Test>::get(sibling_para_id), max);
+	});
+}
+
+#[test]
+fn decay_delivery_fee_works() {
+	use sp_runtime::FixedU128;
+
+	let para: ParaId = 1000.into();
+
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		DeliveryFeeFactor::<Test>::insert(para, FixedU128::from_rational(105 * 105, 100 * 100));
+
+		assert_noop!(XcmpQueue::decay_delivery_fee(Origin::signed(2), para), BadOrigin);
+
+		assert_ok!(XcmpQueue::decay_delivery_fee(Origin::root(), para));
+		assert_eq!(DeliveryFeeFactor::<Test>::get(para), FixedU128::from_float(1.05));
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::DeliveryFeeFactorDecayed {
+				recipient: para,
+				delivery_fee_factor: FixedU128::from_float(1.05),
+			}
+			.into(),
+		);
+
+		// The factor does not drop below the initial factor.
+		assert_ok!(XcmpQueue::decay_delivery_fee(Origin::root(), para));
+		assert_eq!(DeliveryFeeFactor::<Test>::get(para), InitialFactor::get());
+		assert_ok!(XcmpQueue::decay_delivery_fee(Origin::root(), para));
+		assert_eq!(DeliveryFeeFactor::<Test>::get(para), InitialFactor::get());
+	});
+}
+
+#[test]
+fn priority_destination_bypasses_fee_factor_increase() {
+	use cumulus_primitives_core::AbridgedHrmpChannel;
+
+	let priority_para_id = ParaId::from(12345);
+	let other_para_id = ParaId::from(12346);
+	let xcm = Xcm(vec![ClearOrigin; 30]);
+
+	new_test_ext().execute_with(|| {
+		mock::PriorityDestinations::set(vec![priority_para_id]);
+
+		for para_id in [priority_para_id, other_para_id] {
+			ParachainSystem::open_custom_outbound_hrmp_channel_for_benchmarks_or_tests(
+				para_id,
+				AbridgedHrmpChannel {
+					max_capacity: 10,
+					max_total_size: 1000,
+					max_message_size: 104,
+					msg_count: 0,
+					total_size: 0,
+					mqc_head: None,
+				},
+			);
+		}
+
+		// Congest both channels far beyond the threshold.
+		for _ in 0..20 {
+			for para_id in [other_para_id, priority_para_id] {
+				let dest: Location = (Parent, Parachain(para_id.into())).into();
+				assert_ok!(send_xcm::<XcmpQueue>(dest, xcm.clone()));
+			}
+		}
+		assert!(DeliveryFeeFactor::<Test>::get(other_para_id) > InitialFactor::get());
+		assert_eq!(DeliveryFeeFactor::<Test>::get(priority_para_id), InitialFactor::get());
+
+		// The priority channel is serviced first, although the other one was queued first.
+		let taken = XcmpQueue::take_outbound_messages(1);
+		assert_eq!(taken.len(), 1);
+		assert_eq!(taken[0].0, priority_para_id);
+	});
+}
+
+#[test]
+fn page_checksum_works() {
+	// The standard CRC32 check value.
+	assert_eq!(page_checksum(b"123456789"), 0xCBF4_3926);
+	assert_eq!(page_checksum(&[]), 0);
+}
+
+#[test]
+fn page_checksum_matches_known_answers() {
+	// Known answers of the CRC32 used by zlib, gzip and Ethernet.
+	let all_bytes: Vec<u8> = (0..=255).collect();
+	let vectors: [(&[u8], u32); 6] = [
+		(b"a", 0xE8B7_BE43),
+		(b"abc", 0x3524_41C2),
+		(b"The quick brown fox jumps over the lazy dog", 0x414F_A339),
+		(&[0x00; 32][..], 0x190A_55AD),
+		(&[0xFF; 32][..], 0xFF6C_AB0B),
+		(&all_bytes[..], 0x2905_8C73),
+	];
+	for (data, checksum) in vectors {
+		assert_eq!(page_checksum(data), checksum, "checksum of {:?}", data);
+	}
+}
+
+#[test]
+fn checksummed_pages_round_trip() {
+	let sibling_para_id = ParaId::from(12345);
+	let dest: Location = (Parent, Parachain(sibling_para_id.into())).into();
+	let xcms: [Xcm<()>; 2] = [Xcm(vec![ClearOrigin]), Xcm(vec![Trap(5)])];
+
+	new_test_ext().execute_with(|| {
+		mock::EmitPageChecksums::set(true);
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(sibling_para_id);
+
+		for xcm in xcms.iter() {
+			assert_ok!(send_xcm::<XcmpQueue>(dest.clone(), xcm.clone()));
+		}
+
+		let taken = XcmpQueue::take_outbound_messages(usize::MAX);
+		assert_eq!(taken.len(), 1);
+		let (para, page) = &taken[0];
+		assert_eq!(*para, sibling_para_id);
+
+		// The page is the format, followed by the checksum of the concatenated XCMs.
+		let body: Vec<u8> =
+			xcms.iter().flat_map(|xcm| VersionedXcm::from(xcm.clone()).encode()).collect();
+		let expected =
+			[ChecksummedVersionedXcm.encode(), page_checksum(&body).encode(), body].concat();
+		assert_eq!(page, &expected);
+
+		// The receiving side verifies and enqueues all XCMs.
+		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, page.as_slice())), Weight::MAX);
+		assert_eq!(
+			EnqueuedMessages::get(),
+			xcms.iter()
+				.map(|xcm| (ParaId::from(1000), VersionedXcm::from(xcm.clone()).encode()))
+				.collect::<Vec<_>>()
+		);
+	})
+}
+
+#[test]
+fn checksummed_page_mismatch_is_dropped() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		let xcm = VersionedXcm::<Test>::from(Xcm::<Test>(vec![ClearOrigin])).encode();
+		let mut data =
+			[ChecksummedVersionedXcm.encode(), page_checksum(&xcm).encode(), xcm].concat();
+		// Corrupt the checksum.
+		data[1] ^= 1;
+
+		XcmpQueue::handle_xcmp_messages(once((1000.into(), 1, data.as_slice())), Weight::MAX);
+
+		assert!(EnqueuedMessages::get().is_empty());
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::PageChecksumMismatch { sender: 1000.into() }.into(),
+		);
+	})
+}
+
+#[test]
+fn unchecksummed_pages_are_sent_by_default() {
+	let sibling_para_id = ParaId::from(12345);
+	let dest: Location = (Parent, Parachain(sibling_para_id.into())).into();
+
+	new_test_ext().execute_with(|| {
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(sibling_para_id);
+		assert_ok!(send_xcm::<XcmpQueue>(dest, Xcm(vec![ClearOrigin])));
+
+		let taken = XcmpQueue::take_outbound_messages(usize::MAX);
+		assert_eq!(
+			taken,
+			vec![(
+				sibling_para_id,
+				[
+					ConcatenatedVersionedXcm.encode(),
+					VersionedXcm::from(Xcm::<()>(vec![ClearOrigin])).encode()
+				]
+				.concat()
+			)]
+		);
+	})
+}
+
+#[test]
+fn outbound_channel_states_works() {
+	let sibling_para_id = ParaId::from(12345);
+	let dest: Location = (Parent, Parachain(sibling_para_id.into())).into();
+
+	new_test_ext().execute_with(|| {
+		assert!(XcmpQueue::outbound_channel_states().is_empty());
+
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(sibling_para_id);
+		assert_ok!(send_xcm::<XcmpQueue>(dest, Xcm(vec![ClearOrigin])));
+		assert_eq!(
+			XcmpQueue::outbound_channel_states(),
+			vec![(sibling_para_id, OutboundState::Ok, 1)]
+		);
+
+		XcmpQueue::suspend_channel(sibling_para_id);
+		assert_eq!(
+			XcmpQueue::outbound_channel_states(),
+			vec![(sibling_para_id, OutboundState::Suspended, 1)]
+		);
+	})
+}
+
+#[test]
+fn outbound_queued_pages_works() {
+	let sibling_para_id = ParaId::from(12345);
+	let dest: Location = (Parent, Parachain(sibling_para_id.into())).into();
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(XcmpQueue::outbound_queued_pages(sibling_para_id), 0);
+
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(sibling_para_id);
+		assert_ok!(send_xcm::<XcmpQueue>(dest, Xcm(vec![ClearOrigin])));
+		assert_eq!(XcmpQueue::outbound_queued_pages(sibling_para_id), 1);
+		assert_eq!(XcmpQueue::outbound_queued_pages(2000.into()), 0);
+
+		XcmpQueue::take_outbound_messages(usize::MAX);
+		assert_eq!(XcmpQueue::outbound_queued_pages(sibling_para_id), 0);
+	})
+}
+
+#[test]
+fn discard_oversize_outbound_works() {
+	use cumulus_primitives_core::AbridgedHrmpChannel;
+
+	let para = ParaId::from(12345);
+
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		ParachainSystem::open_custom_outbound_hrmp_channel_for_benchmarks_or_tests(
+			para,
+			AbridgedHrmpChannel {
+				max_capacity: 10,
+				max_total_size: 1000,
+				max_message_size: 100,
+				msg_count: 0,
+				total_size: 0,
+				mqc_head: None,
+			},
+		);
+		OutboundXcmpMessages::<Test>::insert(para, 1, vec![0u8; 200]);
+		OutboundXcmpMessages::<Test>::insert(para, 2, vec![0u8; 200]);
+		OutboundXcmpMessages::<Test>::insert(para, 3, vec![0u8; 10]);
+		OutboundXcmpStatus::<Test>::put(vec![OutboundChannelDetails {
+			recipient: para,
+			state: OutboundState::Ok,
+			signals_exist: false,
+			first_index: 1,
+			last_index: 4,
+		}]);
+
+		assert_noop!(XcmpQueue::discard_oversize_outbound(Origin::signed(2), para, 1), BadOrigin);
+		assert_noop!(
+			XcmpQueue::discard_oversize_outbound(Origin::root(), 2000.into(), 1),
+			Error::<Test>::UnknownPage
+		);
+		assert_noop!(
+			XcmpQueue::discard_oversize_outbound(Origin::root(), para, 4),
+			Error::<Test>::UnknownPage
+		);
+		assert_noop!(
+			XcmpQueue::discard_oversize_outbound(Origin::root(), para, 2),
+			Error::<Test>::PageNotAtQueueEdge
+		);
+		assert_noop!(
+			XcmpQueue::discard_oversize_outbound(Origin::root(), para, 3),
+			Error::<Test>::NotOversizePage
+		);
+
+		assert_ok!(XcmpQueue::discard_oversize_outbound(Origin::root(), para, 1));
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::OversizeMessageDiscarded { recipient: para, page_index: 1 }.into(),
+		);
+		assert!(!OutboundXcmpMessages::<Test>::contains_key(para, 1));
+		assert_eq!(XcmpQueue::outbound_queued_pages(para), 2);
+
+		// The next page is now at the head of the queue.
+		assert_ok!(XcmpQueue::discard_oversize_outbound(Origin::root(), para, 2));
+		assert_eq!(XcmpQueue::outbound_queued_pages(para), 1);
+		assert_eq!(XcmpQueue::take_outbound_messages(usize::MAX), vec![(para, vec![0u8; 10])]);
+	})
+}
+
+#[test]
+fn outbound_state_export_import_round_trip_works() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		for (para, pages) in [(1000u32, 3u16), (2000, 0), (3000, 1)] {
+			for page_index in 0..pages {
+				OutboundXcmpMessages::<Test>::insert(
+					ParaId::from(para),
+					page_index,
+					vec![para as u8; page_index as usize + 1],
+				);
+			}
+		}
+		SignalMessages::<Test>::insert(ParaId::from(2000), ChannelSignal::Suspend.encode());
+		let details = |para: u32, pages: u16| OutboundChannelDetails {
+			recipient: para.into(),
+			state: OutboundState::Ok,
+			signals_exist: false,
+			first_index: 0,
+			last_index: pages,
+		};
+		OutboundXcmpStatus::<Test>::put(vec![
+			details(1000, 3),
+			details(2000, 0).with_signals(),
+			details(3000, 1).with_suspended_state(),
+		]);
+
+		let blob = XcmpQueue::export_outbound_state();
+		let snapshot = OutboundStateSnapshot::decode(&mut &blob[..]).unwrap();
+		assert!(snapshot.is_consistent());
+		assert_eq!(snapshot.pages.len(), 4);
+		assert_eq!(snapshot.signals.len(), 1);
+
+		// Change the outbound state.
+		XcmpQueue::take_outbound_messages(usize::MAX);
+		OutboundXcmpMessages::<Test>::insert(ParaId::from(4000), 0, vec![1]);
+		assert_ne!(XcmpQueue::export_outbound_state(), blob);
+
+		let bounded = |blob: Vec<u8>| BoundedVec::try_from(blob).unwrap();
+		let import = |blob: Vec<u8>, queued_items: u32| {
+			XcmpQueue::import_outbound_state(Origin::root(), bounded(blob), queued_items)
+		};
+		assert_noop!(
+			XcmpQueue::import_outbound_state(Origin::signed(2), bounded(blob.clone()), 2),
+			BadOrigin
+		);
+		assert_noop!(import(blob[1..].to_vec(), 1), Error::<Test>::BadOutboundState);
+		// Pages are not allowed outside of the channel indices.
+		let mut inconsistent = snapshot.clone();
+		inconsistent.pages.push((1000.into(), 3, vec![0]));
+		assert_noop!(import(inconsistent.encode(), 1), Error::<Test>::BadOutboundState);
+		// The witness must cover the queued pages and signals.
+		OutboundXcmpStatus::<Test>::put(vec![details(4000, 1)]);
+		assert_noop!(import(blob.clone(), 0), Error::<Test>::BadWitness);
+
+		assert_ok!(import(blob.clone(), 2));
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::OutboundStateImported { channels: 3, items: 5 }.into(),
+		);
+		assert_eq!(XcmpQueue::export_outbound_state(), blob);
+		assert!(!OutboundXcmpMessages::<Test>::contains_key(ParaId::from(4000), 0));
+	})
+}
+
+#[test]
+fn compact_channel_works() {
+	use cumulus_primitives_core::AbridgedHrmpChannel;
+
+	let para = ParaId::from(12345);
+	let xcm = VersionedXcm::<()>::from(Xcm::<()>(vec![ClearOrigin])).encode();
+	let page = |format: XcmpMessageFormat, count: usize| {
+		let mut page = format.encode();
+		page.resize(XcmpQueue::page_header_size(format), 0);
+		for _ in 0..count {
+			page.extend_from_slice(&xcm);
+		}
+		XcmpQueue::update_page_checksum(format, &mut page);
+		page
+	};
+
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		ParachainSystem::open_custom_outbound_hrmp_channel_for_benchmarks_or_tests(
+			para,
+			AbridgedHrmpChannel {
+				max_capacity: 10,
+				max_total_size: 1000,
+				max_message_size: 100,
+				msg_count: 0,
+				total_size: 0,
+				mqc_head: None,
+			},
+		);
+		for page_index in 0..3 {
+			OutboundXcmpMessages::<Test>::insert(
+				para,
+				page_index,
+				page(ConcatenatedVersionedXcm, 1),
+			);
+		}
+		OutboundXcmpMessages::<Test>::insert(para, 3, page(ChecksummedVersionedXcm, 1));
+		OutboundXcmpMessages::<Test>::insert(para, 4, page(ConcatenatedVersionedXcm, 1));
+		OutboundXcmpStatus::<Test>::put(vec![OutboundChannelDetails {
+			recipient: para,
+			state: OutboundState::Ok,
+			signals_exist: false,
+			first_index: 0,
+			last_index: 5,
+		}]);
+
+		assert_noop!(XcmpQueue::compact_channel(Origin::signed(2), para, 4), BadOrigin);
+		assert_noop!(
+			XcmpQueue::compact_channel(Origin::root(), 2000.into(), 4),
+			Error::<Test>::UnknownChannel
+		);
+
+		// The first four pages are compacted, keeping the format boundary.
+		assert_ok!(XcmpQueue::compact_channel(Origin::root(), para, 4));
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::OutboundChannelCompacted { recipient: para, old_pages: 4, new_pages: 2 }
+				.into(),
+		);
+		assert_eq!(XcmpQueue::outbound_queued_pages(para), 3);
+		assert!(!OutboundXcmpMessages::<Test>::contains_key(para, 0));
+		assert!(!OutboundXcmpMessages::<Test>::contains_key(para, 1));
+		assert_eq!(
+			OutboundXcmpMessages::<Test>::get(para, 2),
+			page(ConcatenatedVersionedXcm, 3)
+		);
+		assert_eq!(OutboundXcmpMessages::<Test>::get(para, 3), page(ChecksummedVersionedXcm, 1));
+		assert_eq!(OutboundXcmpMessages::<Test>::get(para, 4), page(ConcatenatedVersionedXcm, 1));
+
+		// Dense pages are left untouched.
+		assert_storage_noop!(assert_ok!(XcmpQueue::compact_channel(Origin::root(), para, 2)));
+	})
+}
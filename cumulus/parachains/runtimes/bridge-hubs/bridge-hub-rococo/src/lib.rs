@@ -46,7 +46,7 @@ use snowbridge_beacon_primitives::{Fork, ForkVersions};
 use snowbridge_core::{
 	gwei, meth,
 	outbound::{Command, Fee},
-	AgentId, AllowSiblingsOnly, PricingParameters, Rewards,
+	AgentId, AllowSiblingsOnly, ChannelId, PricingParameters, Rewards,
 };
 use snowbridge_router_primitives::inbound::MessageToXcm;
 use sp_api::impl_runtime_apis;
@@ -149,6 +149,7 @@ pub type Migrations = (
 	pallet_multisig::migrations::v1::MigrateToV1<Runtime>,
 	InitStorageVersions,
 	cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
+	cumulus_pallet_xcmp_queue::migration::v5::MigrationToV5<Runtime>,
 	// unreleased
 	snowbridge_pallet_system::migration::v0::InitializeOnUpgrade<
 		Runtime,
@@ -418,10 +419,20 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	// Enqueue XCMP messages from siblings for later processing.
 	type XcmpQueue = TransformOrigin<MessageQueue, AggregateMessageOrigin, ParaId, ParaIdToSibling>;
 	type MaxInboundSuspended = sp_core::ConstU32<1_000>;
+	type MaxMessagesPerSenderPerBlock = sp_core::ConstU32<1_000>;
+	type MaxXcmDecodeDepth = sp_core::ConstU32<{ xcm::MAX_XCM_DECODE_DEPTH }>;
 	type ControllerOrigin = EnsureRoot<AccountId>;
 	type ControllerOriginConverter = XcmOriginToTransactDispatchOrigin;
 	type WeightInfo = weights::cumulus_pallet_xcmp_queue::WeightInfo<Runtime>;
 	type PriceForSiblingDelivery = PriceForSiblingParachainDelivery;
+	type EmitPageChecksums = ConstBool<false>;
+	type BlobHandler = ();
+	type MaxQueuedSignals = sp_core::ConstU32<1_000>;
+	type MaxDeliveryFeeFactor = cumulus_pallet_xcmp_queue::DefaultMaxDeliveryFeeFactor;
+	type PriorityDestinations = frame_support::traits::Nothing;
+	type EmitSentAt = ConstBool<false>;
+	type RelayBlockNumberProvider =
+		cumulus_pallet_parachain_system::RelaychainDataProvider<Runtime>;
 }
 
 parameter_types! {
@@ -514,6 +525,8 @@ parameter_types! {
 		rewards: Rewards { local: 1 * UNITS, remote: meth(1) },
 		multiplier: FixedU128::from_rational(1, 1),
 	};
+	pub MinExchangeRate: FixedU128 = FixedU128::from_rational(1, 10_000);
+	pub MaxExchangeRate: FixedU128 = FixedU128::from_rational(1, 10);
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -666,6 +679,8 @@ impl snowbridge_pallet_system::Config for Runtime {
 	type Helper = ();
 	type DefaultPricingParameters = Parameters;
 	type InboundDeliveryCost = EthereumInboundQueue;
+	type MinExchangeRate = MinExchangeRate;
+	type MaxExchangeRate = MaxExchangeRate;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -958,6 +973,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl cumulus_primitives_core::XcmpQueueApi<Block> for Runtime {
+		fn outbound_channel_states() -> Vec<(
+			cumulus_primitives_core::ParaId,
+			cumulus_primitives_core::OutboundState,
+			u16,
+		)> {
+			XcmpQueue::outbound_channel_states()
+		}
+
+		fn is_inbound_suspended(sender: cumulus_primitives_core::ParaId) -> bool {
+			XcmpQueue::is_inbound_suspended(sender)
+		}
+	}
+
 	impl bp_westend::WestendFinalityApi<Block> for Runtime {
 		fn best_finalized() -> Option<HeaderId<bp_westend::Hash, bp_westend::BlockNumber>> {
 			BridgeWestendGrandpa::best_finalized()
@@ -1064,10 +1093,14 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl snowbridge_system_runtime_api::ControlApi<Block> for Runtime {
+	impl snowbridge_system_runtime_api::ControlApi<Block, Balance> for Runtime {
 		fn agent_id(location: VersionedLocation) -> Option<AgentId> {
 			snowbridge_pallet_system::api::agent_id::<Runtime>(location)
 		}
+
+		fn quote_command_fee(channel_id: ChannelId, command: Command) -> Option<(Balance, Balance)> {
+			snowbridge_pallet_system::api::quote_command_fee::<Runtime>(channel_id, command)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
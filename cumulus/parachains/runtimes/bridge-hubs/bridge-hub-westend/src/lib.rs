@@ -129,6 +129,7 @@ pub type Migrations = (
 	InitStorageVersions,
 	// unreleased
 	cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
+	cumulus_pallet_xcmp_queue::migration::v5::MigrationToV5<Runtime>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 );
@@ -380,10 +381,20 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type VersionWrapper = PolkadotXcm;
 	type XcmpQueue = TransformOrigin<MessageQueue, AggregateMessageOrigin, ParaId, ParaIdToSibling>;
 	type MaxInboundSuspended = sp_core::ConstU32<1_000>;
+	type MaxMessagesPerSenderPerBlock = sp_core::ConstU32<1_000>;
+	type MaxXcmDecodeDepth = sp_core::ConstU32<{ xcm::MAX_XCM_DECODE_DEPTH }>;
 	type ControllerOrigin = EnsureRoot<AccountId>;
 	type ControllerOriginConverter = XcmOriginToTransactDispatchOrigin;
 	type WeightInfo = weights::cumulus_pallet_xcmp_queue::WeightInfo<Runtime>;
 	type PriceForSiblingDelivery = PriceForSiblingParachainDelivery;
+	type EmitPageChecksums = ConstBool<false>;
+	type BlobHandler = ();
+	type MaxQueuedSignals = sp_core::ConstU32<1_000>;
+	type MaxDeliveryFeeFactor = cumulus_pallet_xcmp_queue::DefaultMaxDeliveryFeeFactor;
+	type PriorityDestinations = frame_support::traits::Nothing;
+	type EmitSentAt = ConstBool<false>;
+	type RelayBlockNumberProvider =
+		cumulus_pallet_parachain_system::RelaychainDataProvider<Runtime>;
 }
 
 parameter_types! {
@@ -707,6 +718,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl cumulus_primitives_core::XcmpQueueApi<Block> for Runtime {
+		fn outbound_channel_states() -> Vec<(
+			cumulus_primitives_core::ParaId,
+			cumulus_primitives_core::OutboundState,
+			u16,
+		)> {
+			XcmpQueue::outbound_channel_states()
+		}
+
+		fn is_inbound_suspended(sender: cumulus_primitives_core::ParaId) -> bool {
+			XcmpQueue::is_inbound_suspended(sender)
+		}
+	}
+
 	impl bp_rococo::RococoFinalityApi<Block> for Runtime {
 		fn best_finalized() -> Option<HeaderId<bp_rococo::Hash, bp_rococo::BlockNumber>> {
 			BridgeRococoGrandpa::best_finalized()
@@ -372,6 +372,15 @@ pub struct CollationInfo {
 	pub head_data: HeadData,
 }
 
+/// The state of an outbound XCMP channel.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum OutboundState {
+	/// Messages are sent over the channel.
+	Ok,
+	/// The recipient asked to suspend sending messages over the channel.
+	Suspended,
+}
+
 sp_api::decl_runtime_apis! {
 	/// Runtime api to collect information about a collation.
 	#[api_version(2)]
@@ -385,4 +394,14 @@ sp_api::decl_runtime_apis! {
 		/// we are collecting the collation info for.
 		fn collect_collation_info(header: &Block::Header) -> CollationInfo;
 	}
+
+	/// Runtime api to query the state of the XCMP queue.
+	pub trait XcmpQueueApi {
+		/// Returns the recipient, state and number of queued pages of every outbound XCMP channel
+		/// that is tracked by the queue.
+		fn outbound_channel_states() -> Vec<(ParaId, OutboundState, u16)>;
+
+		/// Returns whether the inbound XCMP channel from `sender` is currently suspended.
+		fn is_inbound_suspended(sender: ParaId) -> bool;
+	}
 }
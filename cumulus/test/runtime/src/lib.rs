@@ -394,6 +394,17 @@ decl_runtime_apis! {
 		/// Returns the last timestamp of a runtime.
 		fn get_last_timestamp() -> u64;
 	}
+
+	pub trait GetLastRelayParentNumber {
+		/// Returns the relay parent number the last block was built against, if any block was
+		/// built yet.
+		fn last_relay_parent_number() -> Option<cumulus_primitives_core::relay_chain::BlockNumber>;
+	}
+
+	pub trait GetUnincludedSegmentLen {
+		/// Returns the number of blocks in the unincluded segment.
+		fn unincluded_segment_len() -> u32;
+	}
 }
 
 impl_runtime_apis! {
@@ -505,6 +516,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl crate::GetLastRelayParentNumber<Block> for Runtime {
+		fn last_relay_parent_number() -> Option<cumulus_primitives_core::relay_chain::BlockNumber> {
+			(System::block_number() > 0).then(ParachainSystem::last_relay_block_number)
+		}
+	}
+
+	impl crate::GetUnincludedSegmentLen<Block> for Runtime {
+		fn unincluded_segment_len() -> u32 {
+			ParachainSystem::unincluded_segment_len()
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -14,8 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::collections::{btree_map::BTreeMap, VecDeque};
+use std::{
+	collections::{btree_map::BTreeMap, HashSet, VecDeque},
+	fs,
+	path::PathBuf,
+};
 
+use parity_scale_codec::{Decode, Encode};
 use schnellru::{ByLength, LruMap};
 use sp_consensus_babe::Epoch;
 
@@ -28,11 +33,13 @@ use polkadot_primitives::{
 	ValidationCodeHash, ValidatorId, ValidatorIndex,
 };
 
+use crate::LOG_TARGET;
+
 /// For consistency we have the same capacity for all caches. We use 128 as we'll only need that
 /// much if finality stalls (we only query state for unfinalized blocks + maybe latest finalized).
 /// In any case, a cache is an optimization. We should avoid a situation where having a large cache
 /// leads to OOM or puts pressure on other important stuff like PVF execution/preparation.
-const DEFAULT_CACHE_CAP: u32 = 128;
+pub const DEFAULT_CACHE_CAP: u32 = 128;
 
 pub(crate) struct RequestResultCache {
 	authorities: LruMap<Hash, Vec<AuthorityDiscoveryId>>,
@@ -52,6 +59,7 @@ pub(crate) struct RequestResultCache {
 	candidate_events: LruMap<Hash, Vec<CandidateEvent>>,
 	session_executor_params: LruMap<SessionIndex, Option<ExecutorParams>>,
 	session_info: LruMap<SessionIndex, SessionInfo>,
+	missing_session_info: LruMap<SessionIndex, ()>,
 	dmq_contents: LruMap<(Hash, ParaId), Vec<InboundDownwardMessage<BlockNumber>>>,
 	inbound_hrmp_channels_contents:
 		LruMap<(Hash, ParaId), BTreeMap<ParaId, Vec<InboundHrmpMessage<BlockNumber>>>>,
@@ -71,43 +79,106 @@ pub(crate) struct RequestResultCache {
 	node_features: LruMap<SessionIndex, NodeFeatures>,
 	approval_voting_params: LruMap<SessionIndex, ApprovalVotingParams>,
 	claim_queue: LruMap<Hash, BTreeMap<CoreIndex, VecDeque<ParaId>>>,
+	/// Block numbers of the relay parents we've seen activated, used to prune the
+	/// relay-parent-keyed caches on finality.
+	relay_parent_numbers: LruMap<Hash, BlockNumber>,
+	/// The number of entries evicted from the caches since the last
+	/// [`RequestResultCache::take_evictions`].
+	evictions: u64,
+	/// The maximum number of entries of each cache.
+	capacity: u32,
+	/// Where the session-indexed results are written through to, if anywhere.
+	session_store: Option<SessionStore>,
+	/// The session-indexed results cached since the last
+	/// [`RequestResultCache::take_session_writes`], still to be written to the session store.
+	session_writes: Vec<SessionWrite>,
 }
 
 impl Default for RequestResultCache {
 	fn default() -> Self {
+		Self::new(DEFAULT_CACHE_CAP)
+	}
+}
+
+impl RequestResultCache {
+	/// Create the caches, each holding at most `capacity` entries.
+	pub(crate) fn new(capacity: u32) -> Self {
 		Self {
-			authorities: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			validators: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			validator_groups: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			availability_cores: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			persisted_validation_data: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			assumed_validation_data: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			check_validation_outputs: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			session_index_for_child: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			validation_code: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			validation_code_by_hash: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			candidate_pending_availability: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			candidates_pending_availability: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			candidate_events: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			session_executor_params: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			session_info: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			dmq_contents: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			inbound_hrmp_channels_contents: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			current_babe_epoch: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			on_chain_votes: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			pvfs_require_precheck: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			validation_code_hash: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			version: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			disputes: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			unapplied_slashes: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			key_ownership_proof: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			minimum_backing_votes: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			approval_voting_params: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			disabled_validators: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			para_backing_state: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			async_backing_params: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			node_features: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
-			claim_queue: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
+			authorities: LruMap::new(ByLength::new(capacity)),
+			validators: LruMap::new(ByLength::new(capacity)),
+			validator_groups: LruMap::new(ByLength::new(capacity)),
+			availability_cores: LruMap::new(ByLength::new(capacity)),
+			persisted_validation_data: LruMap::new(ByLength::new(capacity)),
+			assumed_validation_data: LruMap::new(ByLength::new(capacity)),
+			check_validation_outputs: LruMap::new(ByLength::new(capacity)),
+			session_index_for_child: LruMap::new(ByLength::new(capacity)),
+			validation_code: LruMap::new(ByLength::new(capacity)),
+			validation_code_by_hash: LruMap::new(ByLength::new(capacity)),
+			candidate_pending_availability: LruMap::new(ByLength::new(capacity)),
+			candidates_pending_availability: LruMap::new(ByLength::new(capacity)),
+			candidate_events: LruMap::new(ByLength::new(capacity)),
+			session_executor_params: LruMap::new(ByLength::new(capacity)),
+			session_info: LruMap::new(ByLength::new(capacity)),
+			missing_session_info: LruMap::new(ByLength::new(capacity)),
+			dmq_contents: LruMap::new(ByLength::new(capacity)),
+			inbound_hrmp_channels_contents: LruMap::new(ByLength::new(capacity)),
+			current_babe_epoch: LruMap::new(ByLength::new(capacity)),
+			on_chain_votes: LruMap::new(ByLength::new(capacity)),
+			pvfs_require_precheck: LruMap::new(ByLength::new(capacity)),
+			validation_code_hash: LruMap::new(ByLength::new(capacity)),
+			version: LruMap::new(ByLength::new(capacity)),
+			disputes: LruMap::new(ByLength::new(capacity)),
+			unapplied_slashes: LruMap::new(ByLength::new(capacity)),
+			key_ownership_proof: LruMap::new(ByLength::new(capacity)),
+			minimum_backing_votes: LruMap::new(ByLength::new(capacity)),
+			approval_voting_params: LruMap::new(ByLength::new(capacity)),
+			disabled_validators: LruMap::new(ByLength::new(capacity)),
+			para_backing_state: LruMap::new(ByLength::new(capacity)),
+			async_backing_params: LruMap::new(ByLength::new(capacity)),
+			node_features: LruMap::new(ByLength::new(capacity)),
+			claim_queue: LruMap::new(ByLength::new(capacity)),
+			relay_parent_numbers: LruMap::new(ByLength::new(capacity)),
+			evictions: 0,
+			capacity,
+			session_store: None,
+			session_writes: Vec::new(),
+		}
+	}
+
+	/// Persist the session-indexed results in `dir`, and load the ones persisted there before.
+	pub(crate) fn persist_sessions_in(&mut self, dir: PathBuf) {
+		let store = SessionStore { dir, max: self.capacity };
+		for (session_index, info) in store.load(SessionStore::SESSION_INFO) {
+			self.session_info.insert(session_index, info);
+		}
+		for (session_index, features) in store.load(SessionStore::NODE_FEATURES) {
+			self.node_features.insert(session_index, features);
+		}
+		self.session_store = Some(store);
+	}
+
+	/// Take the session-indexed results cached since the last call, to be written to the session
+	/// store with [`SessionWrites::write`] off the subsystem's main loop.
+	///
+	/// Returns `None` if there is nothing to write.
+	pub(crate) fn take_session_writes(&mut self) -> Option<SessionWrites> {
+		let store = self.session_store.clone()?;
+		if self.session_writes.is_empty() {
+			return None
+		}
+		Some(SessionWrites { store, writes: std::mem::take(&mut self.session_writes) })
+	}
+
+	/// Queue `value` to be written to the session store, if there is one.
+	fn queue_session_write<T: Encode>(
+		&mut self,
+		kind: &'static str,
+		session_index: SessionIndex,
+		value: &T,
+	) {
+		if self.session_store.is_some() {
+			self.session_writes
+				.push(SessionWrite { kind, session_index, value: value.encode() });
 		}
 	}
 }
@@ -125,7 +196,7 @@ impl RequestResultCache {
 		relay_parent: Hash,
 		authorities: Vec<AuthorityDiscoveryId>,
 	) {
-		self.authorities.insert(relay_parent, authorities);
+		insert_entry(&mut self.evictions, &mut self.authorities, relay_parent, authorities);
 	}
 
 	pub(crate) fn validators(&mut self, relay_parent: &Hash) -> Option<&Vec<ValidatorId>> {
@@ -133,7 +204,7 @@ impl RequestResultCache {
 	}
 
 	pub(crate) fn cache_validators(&mut self, relay_parent: Hash, validators: Vec<ValidatorId>) {
-		self.validators.insert(relay_parent, validators);
+		insert_entry(&mut self.evictions, &mut self.validators, relay_parent, validators);
 	}
 
 	pub(crate) fn validator_groups(
@@ -148,7 +219,7 @@ impl RequestResultCache {
 		relay_parent: Hash,
 		groups: (Vec<Vec<ValidatorIndex>>, GroupRotationInfo),
 	) {
-		self.validator_groups.insert(relay_parent, groups);
+		insert_entry(&mut self.evictions, &mut self.validator_groups, relay_parent, groups);
 	}
 
 	pub(crate) fn availability_cores(&mut self, relay_parent: &Hash) -> Option<&Vec<CoreState>> {
@@ -156,7 +227,7 @@ impl RequestResultCache {
 	}
 
 	pub(crate) fn cache_availability_cores(&mut self, relay_parent: Hash, cores: Vec<CoreState>) {
-		self.availability_cores.insert(relay_parent, cores);
+		insert_entry(&mut self.evictions, &mut self.availability_cores, relay_parent, cores);
 	}
 
 	pub(crate) fn persisted_validation_data(
@@ -171,7 +242,7 @@ impl RequestResultCache {
 		key: (Hash, ParaId, OccupiedCoreAssumption),
 		data: Option<PersistedValidationData>,
 	) {
-		self.persisted_validation_data.insert(key, data);
+		insert_entry(&mut self.evictions, &mut self.persisted_validation_data, key, data);
 	}
 
 	pub(crate) fn assumed_validation_data(
@@ -186,7 +257,7 @@ impl RequestResultCache {
 		key: (ParaId, Hash),
 		data: Option<(PersistedValidationData, ValidationCodeHash)>,
 	) {
-		self.assumed_validation_data.insert(key, data);
+		insert_entry(&mut self.evictions, &mut self.assumed_validation_data, key, data);
 	}
 
 	pub(crate) fn check_validation_outputs(
@@ -201,7 +272,7 @@ impl RequestResultCache {
 		key: (Hash, ParaId, CandidateCommitments),
 		value: bool,
 	) {
-		self.check_validation_outputs.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.check_validation_outputs, key, value);
 	}
 
 	pub(crate) fn session_index_for_child(&mut self, relay_parent: &Hash) -> Option<&SessionIndex> {
@@ -213,7 +284,7 @@ impl RequestResultCache {
 		relay_parent: Hash,
 		index: SessionIndex,
 	) {
-		self.session_index_for_child.insert(relay_parent, index);
+		insert_entry(&mut self.evictions, &mut self.session_index_for_child, relay_parent, index);
 	}
 
 	pub(crate) fn validation_code(
@@ -228,7 +299,7 @@ impl RequestResultCache {
 		key: (Hash, ParaId, OccupiedCoreAssumption),
 		value: Option<ValidationCode>,
 	) {
-		self.validation_code.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.validation_code, key, value);
 	}
 
 	// the actual key is `ValidationCodeHash` (`Hash` is ignored),
@@ -245,7 +316,7 @@ impl RequestResultCache {
 		key: ValidationCodeHash,
 		value: Option<ValidationCode>,
 	) {
-		self.validation_code_by_hash.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.validation_code_by_hash, key, value);
 	}
 
 	pub(crate) fn candidate_pending_availability(
@@ -260,7 +331,7 @@ impl RequestResultCache {
 		key: (Hash, ParaId),
 		value: Option<CommittedCandidateReceipt>,
 	) {
-		self.candidate_pending_availability.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.candidate_pending_availability, key, value);
 	}
 
 	pub(crate) fn candidates_pending_availability(
@@ -275,7 +346,7 @@ impl RequestResultCache {
 		key: (Hash, ParaId),
 		value: Vec<CommittedCandidateReceipt>,
 	) {
-		self.candidates_pending_availability.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.candidates_pending_availability, key, value);
 	}
 
 	pub(crate) fn candidate_events(&mut self, relay_parent: &Hash) -> Option<&Vec<CandidateEvent>> {
@@ -287,7 +358,7 @@ impl RequestResultCache {
 		relay_parent: Hash,
 		events: Vec<CandidateEvent>,
 	) {
-		self.candidate_events.insert(relay_parent, events);
+		insert_entry(&mut self.evictions, &mut self.candidate_events, relay_parent, events);
 	}
 
 	pub(crate) fn session_info(&mut self, key: SessionIndex) -> Option<&SessionInfo> {
@@ -295,7 +366,21 @@ impl RequestResultCache {
 	}
 
 	pub(crate) fn cache_session_info(&mut self, key: SessionIndex, value: SessionInfo) {
-		self.session_info.insert(key, value);
+		self.queue_session_write(SessionStore::SESSION_INFO, key, &value);
+		insert_entry(&mut self.evictions, &mut self.session_info, key, value);
+	}
+
+	/// Whether the runtime is known to have no `SessionInfo` for session `key`.
+	pub(crate) fn is_session_info_missing(&mut self, key: SessionIndex) -> bool {
+		self.missing_session_info.get(&key).is_some()
+	}
+
+	/// Remember that the runtime has no `SessionInfo` for session `key`.
+	///
+	/// Only sessions before the current one should be recorded here, as the info of any later
+	/// session can still become available.
+	pub(crate) fn cache_missing_session_info(&mut self, key: SessionIndex) {
+		insert_entry(&mut self.evictions, &mut self.missing_session_info, key, ());
 	}
 
 	pub(crate) fn session_executor_params(
@@ -310,7 +395,7 @@ impl RequestResultCache {
 		session_index: SessionIndex,
 		value: Option<ExecutorParams>,
 	) {
-		self.session_executor_params.insert(session_index, value);
+		insert_entry(&mut self.evictions, &mut self.session_executor_params, session_index, value);
 	}
 
 	pub(crate) fn dmq_contents(
@@ -325,7 +410,7 @@ impl RequestResultCache {
 		key: (Hash, ParaId),
 		value: Vec<InboundDownwardMessage<BlockNumber>>,
 	) {
-		self.dmq_contents.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.dmq_contents, key, value);
 	}
 
 	pub(crate) fn inbound_hrmp_channels_contents(
@@ -340,7 +425,7 @@ impl RequestResultCache {
 		key: (Hash, ParaId),
 		value: BTreeMap<ParaId, Vec<InboundHrmpMessage<BlockNumber>>>,
 	) {
-		self.inbound_hrmp_channels_contents.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.inbound_hrmp_channels_contents, key, value);
 	}
 
 	pub(crate) fn current_babe_epoch(&mut self, relay_parent: &Hash) -> Option<&Epoch> {
@@ -348,7 +433,7 @@ impl RequestResultCache {
 	}
 
 	pub(crate) fn cache_current_babe_epoch(&mut self, relay_parent: Hash, epoch: Epoch) {
-		self.current_babe_epoch.insert(relay_parent, epoch);
+		insert_entry(&mut self.evictions, &mut self.current_babe_epoch, relay_parent, epoch);
 	}
 
 	pub(crate) fn on_chain_votes(
@@ -363,7 +448,7 @@ impl RequestResultCache {
 		relay_parent: Hash,
 		scraped: Option<ScrapedOnChainVotes>,
 	) {
-		self.on_chain_votes.insert(relay_parent, scraped);
+		insert_entry(&mut self.evictions, &mut self.on_chain_votes, relay_parent, scraped);
 	}
 
 	pub(crate) fn pvfs_require_precheck(
@@ -378,7 +463,7 @@ impl RequestResultCache {
 		relay_parent: Hash,
 		pvfs: Vec<ValidationCodeHash>,
 	) {
-		self.pvfs_require_precheck.insert(relay_parent, pvfs);
+		insert_entry(&mut self.evictions, &mut self.pvfs_require_precheck, relay_parent, pvfs);
 	}
 
 	pub(crate) fn validation_code_hash(
@@ -393,7 +478,7 @@ impl RequestResultCache {
 		key: (Hash, ParaId, OccupiedCoreAssumption),
 		value: Option<ValidationCodeHash>,
 	) {
-		self.validation_code_hash.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.validation_code_hash, key, value);
 	}
 
 	pub(crate) fn version(&mut self, relay_parent: &Hash) -> Option<&u32> {
@@ -401,7 +486,7 @@ impl RequestResultCache {
 	}
 
 	pub(crate) fn cache_version(&mut self, key: Hash, value: u32) {
-		self.version.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.version, key, value);
 	}
 
 	pub(crate) fn disputes(
@@ -416,7 +501,7 @@ impl RequestResultCache {
 		relay_parent: Hash,
 		value: Vec<(SessionIndex, CandidateHash, DisputeState<BlockNumber>)>,
 	) {
-		self.disputes.insert(relay_parent, value);
+		insert_entry(&mut self.evictions, &mut self.disputes, relay_parent, value);
 	}
 
 	pub(crate) fn unapplied_slashes(
@@ -431,7 +516,7 @@ impl RequestResultCache {
 		relay_parent: Hash,
 		value: Vec<(SessionIndex, CandidateHash, slashing::PendingSlashes)>,
 	) {
-		self.unapplied_slashes.insert(relay_parent, value);
+		insert_entry(&mut self.evictions, &mut self.unapplied_slashes, relay_parent, value);
 	}
 
 	pub(crate) fn key_ownership_proof(
@@ -446,7 +531,7 @@ impl RequestResultCache {
 		key: (Hash, ValidatorId),
 		value: Option<slashing::OpaqueKeyOwnershipProof>,
 	) {
-		self.key_ownership_proof.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.key_ownership_proof, key, value);
 	}
 
 	// This request is never cached, hence always returns `None`.
@@ -466,7 +551,12 @@ impl RequestResultCache {
 		session_index: SessionIndex,
 		minimum_backing_votes: u32,
 	) {
-		self.minimum_backing_votes.insert(session_index, minimum_backing_votes);
+		insert_entry(
+			&mut self.evictions,
+			&mut self.minimum_backing_votes,
+			session_index,
+			minimum_backing_votes,
+		);
 	}
 
 	pub(crate) fn node_features(&mut self, session_index: SessionIndex) -> Option<&NodeFeatures> {
@@ -478,7 +568,8 @@ impl RequestResultCache {
 		session_index: SessionIndex,
 		features: NodeFeatures,
 	) {
-		self.node_features.insert(session_index, features);
+		self.queue_session_write(SessionStore::NODE_FEATURES, session_index, &features);
+		insert_entry(&mut self.evictions, &mut self.node_features, session_index, features);
 	}
 
 	pub(crate) fn disabled_validators(
@@ -493,7 +584,12 @@ impl RequestResultCache {
 		relay_parent: Hash,
 		disabled_validators: Vec<ValidatorIndex>,
 	) {
-		self.disabled_validators.insert(relay_parent, disabled_validators);
+		insert_entry(
+			&mut self.evictions,
+			&mut self.disabled_validators,
+			relay_parent,
+			disabled_validators,
+		);
 	}
 
 	pub(crate) fn para_backing_state(
@@ -508,7 +604,7 @@ impl RequestResultCache {
 		key: (Hash, ParaId),
 		value: Option<async_backing::BackingState>,
 	) {
-		self.para_backing_state.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.para_backing_state, key, value);
 	}
 
 	pub(crate) fn async_backing_params(
@@ -523,7 +619,7 @@ impl RequestResultCache {
 		key: Hash,
 		value: async_backing::AsyncBackingParams,
 	) {
-		self.async_backing_params.insert(key, value);
+		insert_entry(&mut self.evictions, &mut self.async_backing_params, key, value);
 	}
 
 	pub(crate) fn approval_voting_params(
@@ -538,7 +634,7 @@ impl RequestResultCache {
 		session_index: SessionIndex,
 		value: ApprovalVotingParams,
 	) {
-		self.approval_voting_params.insert(session_index, value);
+		insert_entry(&mut self.evictions, &mut self.approval_voting_params, session_index, value);
 	}
 
 	pub(crate) fn claim_queue(
@@ -553,7 +649,178 @@ impl RequestResultCache {
 		relay_parent: Hash,
 		value: BTreeMap<CoreIndex, VecDeque<ParaId>>,
 	) {
-		self.claim_queue.insert(relay_parent, value);
+		insert_entry(&mut self.evictions, &mut self.claim_queue, relay_parent, value);
+	}
+
+	/// Record the block number of a relay parent, so that its entries can be pruned once it is
+	/// finalized.
+	pub(crate) fn note_relay_parent(&mut self, relay_parent: Hash, number: BlockNumber) {
+		self.relay_parent_numbers.insert(relay_parent, number);
+	}
+
+	/// Drop all the entries keyed by the finalized block or by a known relay parent at or below
+	/// the finalized number.
+	///
+	/// Session-keyed entries, as well as those keyed by code or data hashes, are kept.
+	pub(crate) fn prune_finalized(&mut self, hash: Hash, number: BlockNumber) {
+		let mut pruned: HashSet<Hash> = self
+			.relay_parent_numbers
+			.iter()
+			.filter(|(_, n)| **n <= number)
+			.map(|(h, _)| *h)
+			.collect();
+		pruned.insert(hash);
+
+		let is_pruned = |relay_parent: &Hash| pruned.contains(relay_parent);
+
+		prune_where(&mut self.relay_parent_numbers, is_pruned);
+		prune_where(&mut self.authorities, is_pruned);
+		prune_where(&mut self.validators, is_pruned);
+		prune_where(&mut self.validator_groups, is_pruned);
+		prune_where(&mut self.availability_cores, is_pruned);
+		prune_where(&mut self.persisted_validation_data, |(h, _, _)| is_pruned(h));
+		prune_where(&mut self.check_validation_outputs, |(h, _, _)| is_pruned(h));
+		prune_where(&mut self.session_index_for_child, is_pruned);
+		prune_where(&mut self.validation_code, |(h, _, _)| is_pruned(h));
+		prune_where(&mut self.candidate_pending_availability, |(h, _)| is_pruned(h));
+		prune_where(&mut self.candidates_pending_availability, |(h, _)| is_pruned(h));
+		prune_where(&mut self.candidate_events, is_pruned);
+		prune_where(&mut self.dmq_contents, |(h, _)| is_pruned(h));
+		prune_where(&mut self.inbound_hrmp_channels_contents, |(h, _)| is_pruned(h));
+		prune_where(&mut self.current_babe_epoch, is_pruned);
+		prune_where(&mut self.on_chain_votes, is_pruned);
+		prune_where(&mut self.pvfs_require_precheck, is_pruned);
+		prune_where(&mut self.validation_code_hash, |(h, _, _)| is_pruned(h));
+		prune_where(&mut self.version, is_pruned);
+		prune_where(&mut self.disputes, is_pruned);
+		prune_where(&mut self.unapplied_slashes, is_pruned);
+		prune_where(&mut self.key_ownership_proof, |(h, _)| is_pruned(h));
+		prune_where(&mut self.disabled_validators, is_pruned);
+		prune_where(&mut self.para_backing_state, |(h, _)| is_pruned(h));
+		prune_where(&mut self.async_backing_params, is_pruned);
+		prune_where(&mut self.claim_queue, is_pruned);
+	}
+
+	/// Return the number of entries evicted from the caches since the last call, and reset it.
+	pub(crate) fn take_evictions(&mut self) -> u64 {
+		std::mem::take(&mut self.evictions)
+	}
+}
+
+/// Session-indexed results persisted on disk, so that they are still cached after a restart.
+///
+/// Each result is stored SCALE-encoded in its own file, `<dir>/<kind>/<session_index>`, and only
+/// the files of the `max` most recent sessions of each kind are kept. Failing to read or write a
+/// file is not an error, it only makes the result uncached.
+#[derive(Clone)]
+struct SessionStore {
+	dir: PathBuf,
+	max: u32,
+}
+
+impl SessionStore {
+	const SESSION_INFO: &'static str = "session_info";
+	const NODE_FEATURES: &'static str = "node_features";
+
+	/// Write the encoded `value` of `session_index`, then remove the files of the sessions which
+	/// are no longer among the most recent ones.
+	fn write(&self, kind: &str, session_index: SessionIndex, value: &[u8]) {
+		let dir = self.dir.join(kind);
+		let res = fs::create_dir_all(&dir)
+			.and_then(|_| fs::write(dir.join(session_index.to_string()), value));
+		if let Err(err) = res {
+			gum::debug!(
+				target: LOG_TARGET,
+				?err,
+				session_index,
+				kind,
+				"Failed to persist session-indexed runtime API result",
+			);
+		}
+		self.prune(kind);
+	}
+
+	/// Load the results of the most recent sessions of `kind`, removing the older and the
+	/// undecodable ones from disk.
+	fn load<T: Decode>(&self, kind: &str) -> Vec<(SessionIndex, T)> {
+		let dir = self.dir.join(kind);
+		self.prune(kind)
+			.into_iter()
+			.filter_map(|session_index| {
+				let path = dir.join(session_index.to_string());
+				let value = fs::read(&path).ok().and_then(|bytes| T::decode(&mut &bytes[..]).ok());
+				if value.is_none() {
+					let _ = fs::remove_file(&path);
+				}
+				value.map(|value| (session_index, value))
+			})
+			.collect()
+	}
+
+	/// Remove the files of all but the `max` most recent sessions of `kind`, returning the
+	/// sessions that are kept in ascending order.
+	fn prune(&self, kind: &str) -> Vec<SessionIndex> {
+		let dir = self.dir.join(kind);
+		let Ok(entries) = fs::read_dir(&dir) else { return Vec::new() };
+		let mut session_indices: Vec<SessionIndex> = entries
+			.filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+			.collect();
+		session_indices.sort_unstable();
+
+		let first_kept = session_indices.len().saturating_sub(self.max as usize);
+		for session_index in session_indices.drain(..first_kept) {
+			let _ = fs::remove_file(dir.join(session_index.to_string()));
+		}
+		session_indices
+	}
+}
+
+/// An encoded session-indexed result to be written to the [`SessionStore`].
+struct SessionWrite {
+	kind: &'static str,
+	session_index: SessionIndex,
+	value: Vec<u8>,
+}
+
+/// A batch of session-indexed results to be written to the session store.
+///
+/// Writing does blocking file system I/O, so it should not happen on the subsystem's main loop.
+pub(crate) struct SessionWrites {
+	store: SessionStore,
+	writes: Vec<SessionWrite>,
+}
+
+impl SessionWrites {
+	/// Write the results to the session store.
+	pub(crate) fn write(self) {
+		for SessionWrite { kind, session_index, value } in self.writes {
+			self.store.write(kind, session_index, &value);
+		}
+	}
+}
+
+/// Insert `value` into `map`, counting the entry it evicts in `evictions` if the map is full.
+fn insert_entry<K, V>(evictions: &mut u64, map: &mut LruMap<K, V>, key: K, value: V)
+where
+	K: std::hash::Hash + PartialEq,
+{
+	let len = map.len();
+	let is_new = map.peek(&key).is_none();
+	map.insert(key, value);
+	// Inserting a new key into a full map evicts the least recently used entry.
+	if is_new && map.len() <= len {
+		*evictions += 1;
+	}
+}
+
+/// Remove all the entries of `map` whose key matches `should_prune`.
+fn prune_where<K, V>(map: &mut LruMap<K, V>, should_prune: impl Fn(&K) -> bool)
+where
+	K: std::hash::Hash + PartialEq + Clone,
+{
+	let keys: Vec<K> = map.iter().map(|(k, _)| k).filter(|k| should_prune(k)).cloned().collect();
+	for key in keys {
+		map.remove(&key);
 	}
 }
 
@@ -563,6 +830,7 @@ pub(crate) enum RequestResult {
 	Validators(Hash, Vec<ValidatorId>),
 	MinimumBackingVotes(SessionIndex, u32),
 	ValidatorGroups(Hash, (Vec<Vec<ValidatorIndex>>, GroupRotationInfo)),
+	ValidatorsAndGroups(Hash, Vec<ValidatorId>, (Vec<Vec<ValidatorIndex>>, GroupRotationInfo)),
 	AvailabilityCores(Hash, Vec<CoreState>),
 	PersistedValidationData(Hash, ParaId, OccupiedCoreAssumption, Option<PersistedValidationData>),
 	AssumedValidationData(
@@ -605,5 +873,6 @@ pub(crate) enum RequestResult {
 	AsyncBackingParams(Hash, async_backing::AsyncBackingParams),
 	NodeFeatures(SessionIndex, NodeFeatures),
 	ClaimQueue(Hash, BTreeMap<CoreIndex, VecDeque<ParaId>>),
+	CoresAndClaimQueue(Hash, Vec<CoreState>, BTreeMap<CoreIndex, VecDeque<ParaId>>),
 	CandidatesPendingAvailability(Hash, ParaId, Vec<CommittedCandidateReceipt>),
 }
@@ -31,10 +31,18 @@ use polkadot_node_subsystem_types::RuntimeApiSubsystemClient;
 use polkadot_primitives::Hash;
 
 use cache::{RequestResult, RequestResultCache};
-use futures::{channel::oneshot, prelude::*, select, stream::FuturesUnordered};
-use std::sync::Arc;
+use futures::{
+	channel::oneshot, future::BoxFuture, prelude::*, select, stream::FuturesUnordered,
+};
+use std::{
+	collections::{hash_map::Entry, HashMap},
+	mem::{self, Discriminant},
+	path::PathBuf,
+	sync::Arc,
+};
 
 mod cache;
+pub use cache::DEFAULT_CACHE_CAP;
 
 mod metrics;
 use self::metrics::Metrics;
@@ -51,32 +59,112 @@ const MAX_PARALLEL_REQUESTS: usize = 4;
 /// The name of the blocking task that executes a runtime API request.
 const API_REQUEST_TASK_NAME: &str = "polkadot-runtime-api-request";
 
+/// The name of the blocking tasks writing session-indexed results to disk.
+const SESSION_WRITE_TASK_NAME: &str = "polkadot-runtime-api-session-write";
+
+/// Identifies a request that identical requests can wait on instead of being executed again.
+type InFlightKey = (Hash, Discriminant<Request>);
+
+/// A runtime API request being executed, resolving to its key and result.
+type ActiveRequest = BoxFuture<'static, (Option<InFlightKey>, Option<RequestResult>)>;
+
 /// The `RuntimeApiSubsystem`. See module docs for more details.
 pub struct RuntimeApiSubsystem<Client> {
 	client: Arc<Client>,
 	metrics: Metrics,
 	spawn_handle: Box<dyn overseer::gen::Spawner>,
 	/// All the active runtime API requests that are currently being executed.
-	active_requests: FuturesUnordered<oneshot::Receiver<Option<RequestResult>>>,
+	active_requests: FuturesUnordered<ActiveRequest>,
+	/// The requests waiting for an identical active request to finish.
+	in_flight: HashMap<InFlightKey, Vec<Request>>,
 	/// Requests results cache
 	requests_cache: RequestResultCache,
+	/// The requests issued for every new leaf, to have their results cached before they are
+	/// needed.
+	prefetch: Vec<PrefetchRequest>,
+}
+
+/// A runtime API request that can be issued for every new leaf before it is needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefetchRequest {
+	/// Prefetch `RuntimeApiRequest::Validators`.
+	Validators,
+	/// Prefetch `RuntimeApiRequest::ValidatorGroups`.
+	ValidatorGroups,
+	/// Prefetch `RuntimeApiRequest::AvailabilityCores`.
+	AvailabilityCores,
+	/// Prefetch `RuntimeApiRequest::SessionIndexForChild`.
+	SessionIndexForChild,
+	/// Prefetch `RuntimeApiRequest::DisabledValidators`.
+	DisabledValidators,
+	/// Prefetch `RuntimeApiRequest::AsyncBackingParams`.
+	AsyncBackingParams,
+	/// Prefetch `RuntimeApiRequest::ClaimQueue`.
+	ClaimQueue,
+}
+
+impl PrefetchRequest {
+	/// The request to issue for a new leaf.
+	fn into_request(self) -> Request {
+		// The receivers are dropped, the results are only cached.
+		match self {
+			PrefetchRequest::Validators => Request::Validators(oneshot::channel().0),
+			PrefetchRequest::ValidatorGroups => Request::ValidatorGroups(oneshot::channel().0),
+			PrefetchRequest::AvailabilityCores => Request::AvailabilityCores(oneshot::channel().0),
+			PrefetchRequest::SessionIndexForChild =>
+				Request::SessionIndexForChild(oneshot::channel().0),
+			PrefetchRequest::DisabledValidators =>
+				Request::DisabledValidators(oneshot::channel().0),
+			PrefetchRequest::AsyncBackingParams =>
+				Request::AsyncBackingParams(oneshot::channel().0),
+			PrefetchRequest::ClaimQueue => Request::ClaimQueue(oneshot::channel().0),
+		}
+	}
 }
 
 impl<Client> RuntimeApiSubsystem<Client> {
 	/// Create a new Runtime API subsystem wrapping the given client and metrics.
+	///
+	/// Each kind of request result is cached for at most `cache_capacity` relay parents or
+	/// sessions, see [`DEFAULT_CACHE_CAP`] for a sensible default.
 	pub fn new(
 		client: Arc<Client>,
 		metrics: Metrics,
 		spawner: impl overseer::gen::Spawner + 'static,
+		cache_capacity: u32,
+	) -> Self {
+		Self::new_with_prefetch(client, metrics, spawner, cache_capacity, Vec::new())
+	}
+
+	/// Create a new Runtime API subsystem which issues the `prefetch` requests for every new
+	/// leaf, so that their results are already cached when they are first needed.
+	pub fn new_with_prefetch(
+		client: Arc<Client>,
+		metrics: Metrics,
+		spawner: impl overseer::gen::Spawner + 'static,
+		cache_capacity: u32,
+		prefetch: Vec<PrefetchRequest>,
 	) -> Self {
 		RuntimeApiSubsystem {
 			client,
 			metrics,
 			spawn_handle: Box::new(spawner),
 			active_requests: Default::default(),
-			requests_cache: RequestResultCache::default(),
+			in_flight: Default::default(),
+			requests_cache: RequestResultCache::new(cache_capacity),
+			prefetch,
 		}
 	}
+
+	/// Persist the session-indexed results, like `SessionInfo` and `NodeFeatures`, in `dir`.
+	///
+	/// The results persisted there by a previous instance are loaded right away, so that they are
+	/// served from the cache after a restart instead of calling into the runtime again. New results
+	/// are written in blocking tasks, and only the ones of the most recent sessions are kept.
+	pub fn with_session_cache_dir(mut self, dir: PathBuf) -> Self {
+		self.requests_cache.persist_sessions_in(dir);
+		self
+	}
 }
 
 #[overseer::subsystem(RuntimeApi, error = SubsystemError, prefix = self::overseer)]
@@ -106,6 +194,10 @@ where
 				.cache_minimum_backing_votes(session_index, minimum_backing_votes),
 			ValidatorGroups(relay_parent, groups) =>
 				self.requests_cache.cache_validator_groups(relay_parent, groups),
+			ValidatorsAndGroups(relay_parent, validators, groups) => {
+				self.requests_cache.cache_validators(relay_parent, validators);
+				self.requests_cache.cache_validator_groups(relay_parent, groups);
+			},
 			AvailabilityCores(relay_parent, cores) =>
 				self.requests_cache.cache_availability_cores(relay_parent, cores),
 			PersistedValidationData(relay_parent, para_id, assumption, data) => self
@@ -140,9 +232,16 @@ where
 				self.requests_cache.cache_candidate_events(relay_parent, events),
 			SessionExecutorParams(_relay_parent, session_index, index) =>
 				self.requests_cache.cache_session_executor_params(session_index, index),
-			SessionInfo(_relay_parent, session_index, info) =>
+			SessionInfo(relay_parent, session_index, info) =>
 				if let Some(info) = info {
 					self.requests_cache.cache_session_info(session_index, info);
+				} else if self
+					.requests_cache
+					.session_index_for_child(&relay_parent)
+					.map_or(false, |current| session_index < *current)
+				{
+					// The info of a past session never becomes available again.
+					self.requests_cache.cache_missing_session_info(session_index);
 				},
 			DmqContents(relay_parent, para_id, messages) =>
 				self.requests_cache.cache_dmq_contents((relay_parent, para_id), messages),
@@ -183,6 +282,20 @@ where
 			ClaimQueue(relay_parent, sender) => {
 				self.requests_cache.cache_claim_queue(relay_parent, sender);
 			},
+			CoresAndClaimQueue(relay_parent, cores, claim_queue) => {
+				self.requests_cache.cache_availability_cores(relay_parent, cores);
+				self.requests_cache.cache_claim_queue(relay_parent, claim_queue);
+			},
+		}
+
+		self.metrics.on_cache_evictions(self.requests_cache.take_evictions());
+
+		if let Some(writes) = self.requests_cache.take_session_writes() {
+			self.spawn_handle.spawn_blocking(
+				SESSION_WRITE_TASK_NAME,
+				Some("runtime-api"),
+				async move { writes.write() }.boxed(),
+			);
 		}
 	}
 
@@ -221,6 +334,18 @@ where
 				query!(validators(), sender).map(|sender| Request::Validators(sender)),
 			Request::ValidatorGroups(sender) =>
 				query!(validator_groups(), sender).map(|sender| Request::ValidatorGroups(sender)),
+			Request::ValidatorsAndGroups(sender) => {
+				let validators = self.requests_cache.validators(&relay_parent).cloned();
+				let groups = self.requests_cache.validator_groups(&relay_parent).cloned();
+				match validators.zip(groups) {
+					Some(value) => {
+						let _ = sender.send(Ok(value));
+						self.metrics.on_cached_request();
+						None
+					},
+					None => Some(Request::ValidatorsAndGroups(sender)),
+				}
+			},
 			Request::AvailabilityCores(sender) => query!(availability_cores(), sender)
 				.map(|sender| Request::AvailabilityCores(sender)),
 			Request::PersistedValidationData(para, assumption, sender) =>
@@ -276,6 +401,10 @@ where
 					self.metrics.on_cached_request();
 					let _ = sender.send(Ok(Some(info.clone())));
 					None
+				} else if self.requests_cache.is_session_info_missing(index) {
+					self.metrics.on_cached_request();
+					let _ = sender.send(Ok(None));
+					None
 				} else {
 					Some(Request::SessionInfo(index, sender))
 				}
@@ -340,6 +469,18 @@ where
 			},
 			Request::ClaimQueue(sender) =>
 				query!(claim_queue(), sender).map(|sender| Request::ClaimQueue(sender)),
+			Request::CoresAndClaimQueue(sender) => {
+				let cores = self.requests_cache.availability_cores(&relay_parent).cloned();
+				let claim_queue = self.requests_cache.claim_queue(&relay_parent).cloned();
+				match cores.zip(claim_queue) {
+					Some(value) => {
+						let _ = sender.send(Ok(value));
+						self.metrics.on_cached_request();
+						None
+					},
+					None => Some(Request::CoresAndClaimQueue(sender)),
+				}
+			},
 		}
 	}
 
@@ -355,6 +496,20 @@ where
 			None => return,
 		};
 
+		let key = coalescing_kind(&request).map(|kind| (relay_parent, kind));
+		if let Some(key) = key {
+			match self.in_flight.entry(key) {
+				Entry::Occupied(mut waiting) => {
+					waiting.get_mut().push(request);
+					return
+				},
+				Entry::Vacant(entry) => {
+					entry.insert(Vec::new());
+				},
+			}
+		}
+
+		self.metrics.on_uncached_request();
 		let request = async move {
 			let result = make_runtime_api_request(client, metrics, relay_parent, request).await;
 			let _ = sender.send(result);
@@ -363,7 +518,8 @@ where
 
 		self.spawn_handle
 			.spawn_blocking(API_REQUEST_TASK_NAME, Some("runtime-api"), request);
-		self.active_requests.push(receiver);
+		self.active_requests
+			.push(receiver.map(move |result| (key, result.ok().flatten())).boxed());
 	}
 
 	/// Poll the active runtime API requests.
@@ -375,8 +531,29 @@ where
 
 		// If there are active requests, this will always resolve to `Some(_)` when a request is
 		// finished.
-		if let Some(Ok(Some(result))) = self.active_requests.next().await {
-			self.store_cache(result);
+		if let Some((key, result)) = self.active_requests.next().await {
+			if let Some(result) = result {
+				self.store_cache(result);
+			}
+
+			// The waiting requests are answered from the cache now, or executed again if the
+			// result could not be cached.
+			if let Some(key) = key {
+				for request in self.in_flight.remove(&key).unwrap_or_default() {
+					self.spawn_request(key.0, request);
+				}
+			}
+		}
+	}
+
+	/// Issue the prefetch requests for the new leaf `relay_parent`.
+	async fn prefetch(&mut self, relay_parent: Hash) {
+		for request in self.prefetch.clone() {
+			// Respect the limit of parallel requests, as `run` does.
+			if self.is_busy() {
+				self.poll_requests().await;
+			}
+			self.spawn_request(relay_parent, request.into_request());
 		}
 	}
 
@@ -386,6 +563,33 @@ where
 	}
 }
 
+/// The kind of `request` if identical requests for the same relay parent can wait on it
+/// instead of being executed again.
+///
+/// This is the case for the cached requests that only depend on the relay parent.
+fn coalescing_kind(request: &Request) -> Option<Discriminant<Request>> {
+	match request {
+		Request::Version(_) |
+		Request::Authorities(_) |
+		Request::Validators(_) |
+		Request::ValidatorGroups(_) |
+		Request::ValidatorsAndGroups(_) |
+		Request::AvailabilityCores(_) |
+		Request::SessionIndexForChild(_) |
+		Request::CandidateEvents(_) |
+		Request::CurrentBabeEpoch(_) |
+		Request::FetchOnChainVotes(_) |
+		Request::PvfsRequirePrecheck(_) |
+		Request::Disputes(_) |
+		Request::UnappliedSlashes(_) |
+		Request::DisabledValidators(_) |
+		Request::AsyncBackingParams(_) |
+		Request::ClaimQueue(_) |
+		Request::CoresAndClaimQueue(_) => Some(mem::discriminant(request)),
+		_ => None,
+	}
+}
+
 #[overseer::contextbounds(RuntimeApi, prefix = self::overseer)]
 async fn run<Client, Context>(
 	mut ctx: Context,
@@ -409,8 +613,17 @@ where
 		select! {
 			req = ctx.recv().fuse() => match req? {
 				FromOrchestra::Signal(OverseerSignal::Conclude) => return Ok(()),
-				FromOrchestra::Signal(OverseerSignal::ActiveLeaves(_)) => {},
-				FromOrchestra::Signal(OverseerSignal::BlockFinalized(..)) => {},
+				FromOrchestra::Signal(OverseerSignal::ActiveLeaves(update)) => {
+					if let Some(activated) = update.activated {
+						subsystem
+							.requests_cache
+							.note_relay_parent(activated.hash, activated.number);
+						subsystem.prefetch(activated.hash).await;
+					}
+				},
+				FromOrchestra::Signal(OverseerSignal::BlockFinalized(hash, number)) => {
+					subsystem.requests_cache.prune_finalized(hash, number);
+				},
 				FromOrchestra::Communication { msg } => match msg {
 					RuntimeApiMessage::Request(relay_parent, request) => {
 						subsystem.spawn_request(relay_parent, request);
@@ -459,6 +672,7 @@ where
 				});
 
 			let res = if runtime_version >= version {
+				let _timer = metrics.time_runtime_api_request(stringify!($api_name));
 				client.$api_name(relay_parent $(, $param.clone() )*).await
 					.map_err(|e| RuntimeApiError::Execution {
 						runtime_api_name: stringify!($api_name),
@@ -469,7 +683,7 @@ where
 					runtime_api_name: stringify!($api_name),
 				})
 			};
-			metrics.on_request(res.is_ok());
+			metrics.on_request(&res);
 			let _ = sender.send(res.clone());
 
 			res.ok().map(|res| RequestResult::$req_variant($( $results, )* res))
@@ -496,6 +710,31 @@ where
 		Request::ValidatorGroups(sender) => {
 			query!(ValidatorGroups, validator_groups(), ver = 1, sender)
 		},
+		Request::ValidatorsAndGroups(sender) => {
+			let execution_error = |runtime_api_name: &'static str, e: sp_api::ApiError| {
+				RuntimeApiError::Execution { runtime_api_name, source: std::sync::Arc::new(e) }
+			};
+			let res = async {
+				let validators = {
+					let _timer = metrics.time_runtime_api_request("validators");
+					client.validators(relay_parent).await
+				}
+				.map_err(|e| execution_error("validators", e))?;
+				let groups = {
+					let _timer = metrics.time_runtime_api_request("validator_groups");
+					client.validator_groups(relay_parent).await
+				}
+				.map_err(|e| execution_error("validator_groups", e))?;
+				Ok::<_, RuntimeApiError>((validators, groups))
+			}
+			.await;
+			metrics.on_request(&res);
+			let _ = sender.send(res.clone());
+
+			res.ok().map(|(validators, groups)| {
+				RequestResult::ValidatorsAndGroups(relay_parent, validators, groups)
+			})
+		},
 		Request::AvailabilityCores(sender) => {
 			query!(AvailabilityCores, availability_cores(), ver = 1, sender)
 		},
@@ -652,5 +891,36 @@ where
 			ver = Request::CLAIM_QUEUE_RUNTIME_REQUIREMENT,
 			sender
 		),
+		Request::CoresAndClaimQueue(sender) => {
+			let execution_error = |runtime_api_name: &'static str, e: sp_api::ApiError| {
+				RuntimeApiError::Execution { runtime_api_name, source: std::sync::Arc::new(e) }
+			};
+			let res = async {
+				let runtime_version =
+					client.api_version_parachain_host(relay_parent).await.ok().flatten();
+				if runtime_version.unwrap_or(0) < Request::CLAIM_QUEUE_RUNTIME_REQUIREMENT {
+					return Err(RuntimeApiError::NotSupported { runtime_api_name: "claim_queue" })
+				}
+
+				let cores = {
+					let _timer = metrics.time_runtime_api_request("availability_cores");
+					client.availability_cores(relay_parent).await
+				}
+				.map_err(|e| execution_error("availability_cores", e))?;
+				let claim_queue = {
+					let _timer = metrics.time_runtime_api_request("claim_queue");
+					client.claim_queue(relay_parent).await
+				}
+				.map_err(|e| execution_error("claim_queue", e))?;
+				Ok::<_, RuntimeApiError>((cores, claim_queue))
+			}
+			.await;
+			metrics.on_request(&res);
+			let _ = sender.send(res.clone());
+
+			res.ok().map(|(cores, claim_queue)| {
+				RequestResult::CoresAndClaimQueue(relay_parent, cores, claim_queue)
+			})
+		},
 	}
 }
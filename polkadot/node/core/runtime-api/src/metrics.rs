@@ -15,11 +15,26 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use polkadot_node_metrics::metrics::{self, prometheus};
+use polkadot_node_subsystem::errors::RuntimeApiError;
 
 #[derive(Clone)]
 pub(crate) struct MetricsInner {
 	pub(crate) chain_api_requests: prometheus::CounterVec<prometheus::U64>,
 	pub(crate) make_runtime_api_request: prometheus::Histogram,
+	pub(crate) runtime_api_request: prometheus::HistogramVec,
+	pub(crate) uncached_requests: prometheus::Counter<prometheus::U64>,
+	pub(crate) cache_hit_ratio: prometheus::Gauge<prometheus::F64>,
+	pub(crate) cache_evictions: prometheus::Counter<prometheus::U64>,
+}
+
+impl MetricsInner {
+	fn update_cache_hit_ratio(&self) {
+		let hits = self.chain_api_requests.with_label_values(&["cached"]).get();
+		let total = hits + self.uncached_requests.get();
+		if total > 0 {
+			self.cache_hit_ratio.set(hits as f64 / total as f64);
+		}
+	}
 }
 
 /// Runtime API metrics.
@@ -27,20 +42,40 @@ pub(crate) struct MetricsInner {
 pub struct Metrics(pub(crate) Option<MetricsInner>);
 
 impl Metrics {
-	pub fn on_request(&self, succeeded: bool) {
+	/// Record the result of a request made to the runtime.
+	///
+	/// Requests the runtime is too old for are counted apart from the ones that failed.
+	pub fn on_request<T>(&self, res: &Result<T, RuntimeApiError>) {
 		if let Some(metrics) = &self.0 {
-			if succeeded {
-				metrics.chain_api_requests.with_label_values(&["succeeded"]).inc();
-			} else {
-				metrics.chain_api_requests.with_label_values(&["failed"]).inc();
-			}
+			let label = match res {
+				Ok(_) => "succeeded",
+				Err(RuntimeApiError::NotSupported { .. }) => "not_supported",
+				Err(RuntimeApiError::Execution { .. }) => "failed",
+			};
+			metrics.chain_api_requests.with_label_values(&[label]).inc();
 		}
 	}
 
 	pub fn on_cached_request(&self) {
-		self.0
-			.as_ref()
-			.map(|metrics| metrics.chain_api_requests.with_label_values(&["cached"]).inc());
+		if let Some(metrics) = &self.0 {
+			metrics.chain_api_requests.with_label_values(&["cached"]).inc();
+			metrics.update_cache_hit_ratio();
+		}
+	}
+
+	/// Record a request that could not be served from the cache.
+	pub fn on_uncached_request(&self) {
+		if let Some(metrics) = &self.0 {
+			metrics.uncached_requests.inc();
+			metrics.update_cache_hit_ratio();
+		}
+	}
+
+	/// Record `count` entries evicted from the cache.
+	pub fn on_cache_evictions(&self, count: u64) {
+		if let Some(metrics) = &self.0 {
+			metrics.cache_evictions.inc_by(count);
+		}
 	}
 
 	/// Provide a timer for `make_runtime_api_request` which observes on drop.
@@ -49,6 +84,16 @@ impl Metrics {
 	) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
 		self.0.as_ref().map(|metrics| metrics.make_runtime_api_request.start_timer())
 	}
+
+	/// Provide a timer for a call of the runtime API `api` which observes on drop.
+	pub fn time_runtime_api_request(
+		&self,
+		api: &'static str,
+	) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
+		self.0
+			.as_ref()
+			.map(|metrics| metrics.runtime_api_request.with_label_values(&[api]).start_timer())
+	}
 }
 
 impl metrics::Metrics for Metrics {
@@ -71,6 +116,37 @@ impl metrics::Metrics for Metrics {
 				))?,
 				registry,
 			)?,
+			runtime_api_request: prometheus::register(
+				prometheus::HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_runtime_api_request_duration",
+						"Time spent executing a Runtime API call, by the API called",
+					),
+					&["api"],
+				)?,
+				registry,
+			)?,
+			uncached_requests: prometheus::register(
+				prometheus::Counter::new(
+					"polkadot_parachain_runtime_api_uncached_requests_total",
+					"Number of Runtime API requests that could not be served from the cache.",
+				)?,
+				registry,
+			)?,
+			cache_hit_ratio: prometheus::register(
+				prometheus::Gauge::new(
+					"polkadot_parachain_runtime_api_cache_hit_ratio",
+					"Share of the Runtime API requests served from the cache.",
+				)?,
+				registry,
+			)?,
+			cache_evictions: prometheus::register(
+				prometheus::Counter::new(
+					"polkadot_parachain_runtime_api_cache_evictions_total",
+					"Number of Runtime API request results evicted from the cache.",
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
@@ -40,6 +40,7 @@ struct MockSubsystemClient {
 	submitted_pvf_check_statement: Arc<Mutex<Vec<(PvfCheckStatement, ValidatorSignature)>>>,
 	authorities: Vec<AuthorityDiscoveryId>,
 	validators: Vec<ValidatorId>,
+	validators_requests: Arc<Mutex<u32>>,
 	validator_groups: Vec<Vec<ValidatorIndex>>,
 	availability_cores: Vec<CoreState>,
 	validation_data: HashMap<ParaId, PersistedValidationData>,
@@ -57,15 +58,19 @@ struct MockSubsystemClient {
 	validation_code_hash: HashMap<ParaId, ValidationCodeHash>,
 	session_info: HashMap<SessionIndex, SessionInfo>,
 	candidate_events: Vec<CandidateEvent>,
+	claim_queue: BTreeMap<CoreIndex, VecDeque<ParaId>>,
+	/// Overrides the runtime API version reported by the runtime.
+	api_version: Option<u32>,
 }
 
 #[async_trait::async_trait]
 impl RuntimeApiSubsystemClient for MockSubsystemClient {
 	async fn api_version_parachain_host(&self, _: Hash) -> Result<Option<u32>, ApiError> {
-		Ok(Some(5))
+		Ok(Some(self.api_version.unwrap_or(Request::CLAIM_QUEUE_RUNTIME_REQUIREMENT)))
 	}
 
 	async fn validators(&self, _: Hash) -> Result<Vec<ValidatorId>, ApiError> {
+		*self.validators_requests.lock().unwrap() += 1;
 		Ok(self.validators.clone())
 	}
 
@@ -299,7 +304,7 @@ impl RuntimeApiSubsystemClient for MockSubsystemClient {
 		&self,
 		_: Hash,
 	) -> Result<BTreeMap<CoreIndex, VecDeque<ParaId>>, ApiError> {
-		todo!("Not required for tests")
+		Ok(self.claim_queue.clone())
 	}
 }
 
@@ -310,8 +315,12 @@ fn requests_authorities() {
 	let relay_parent = [1; 32].into();
 	let spawner = sp_core::testing::TaskExecutor::new();
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -337,8 +346,12 @@ fn requests_validators() {
 	let relay_parent = [1; 32].into();
 	let spawner = sp_core::testing::TaskExecutor::new();
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -357,6 +370,119 @@ fn requests_validators() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn identical_requests_in_flight_are_coalesced() {
+	let subsystem_client = Arc::new(MockSubsystemClient::default());
+	let relay_parent = [1; 32].into();
+	let spawner = sp_core::testing::TaskExecutor::new();
+
+	let mut subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
+
+	let (tx_a, rx_a) = oneshot::channel();
+	let (tx_b, rx_b) = oneshot::channel();
+	subsystem.spawn_request(relay_parent, Request::Validators(tx_a));
+	subsystem.spawn_request(relay_parent, Request::Validators(tx_b));
+
+	// The second request waits on the first one.
+	assert_eq!(subsystem.active_requests.len(), 1);
+
+	futures::executor::block_on(subsystem.poll_requests());
+	assert!(subsystem.active_requests.is_empty());
+	assert!(subsystem.in_flight.is_empty());
+
+	let validators = futures::executor::block_on(future::join(rx_a, rx_b));
+	assert_eq!(validators.0.unwrap().unwrap(), subsystem_client.validators);
+	assert_eq!(validators.1.unwrap().unwrap(), subsystem_client.validators);
+	assert_eq!(*subsystem_client.validators_requests.lock().unwrap(), 1);
+}
+
+#[test]
+fn prefetch_caches_results_for_new_leaf() {
+	let subsystem_client = Arc::new(MockSubsystemClient::default());
+	let relay_parent = [1; 32].into();
+	let spawner = sp_core::testing::TaskExecutor::new();
+
+	let mut subsystem = RuntimeApiSubsystem::new_with_prefetch(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+		vec![PrefetchRequest::Validators, PrefetchRequest::SessionIndexForChild],
+	);
+
+	futures::executor::block_on(async {
+		subsystem.prefetch(relay_parent).await;
+		while !subsystem.active_requests.is_empty() {
+			subsystem.poll_requests().await;
+		}
+	});
+
+	assert_eq!(
+		subsystem.requests_cache.validators(&relay_parent),
+		Some(&subsystem_client.validators)
+	);
+	assert_eq!(
+		subsystem.requests_cache.session_index_for_child(&relay_parent),
+		Some(&subsystem_client.session_index_for_child)
+	);
+	// Only the configured requests are prefetched.
+	assert!(subsystem.requests_cache.availability_cores(&relay_parent).is_none());
+}
+
+#[test]
+fn block_finalized_prunes_relay_parent_entries() {
+	let mut cache = cache::RequestResultCache::default();
+	let (finalized, ancestor, descendant) = ([1; 32].into(), [2; 32].into(), [3; 32].into());
+	let validators = vec![sp_keyring::Sr25519Keyring::Alice.public().into()];
+
+	cache.note_relay_parent(ancestor, 1);
+	cache.note_relay_parent(descendant, 3);
+	for relay_parent in [finalized, ancestor, descendant] {
+		cache.cache_validators(relay_parent, validators.clone());
+		cache.cache_session_index_for_child(relay_parent, 1);
+	}
+	cache.cache_minimum_backing_votes(1, 2);
+
+	cache.prune_finalized(finalized, 2);
+
+	// Entries at or below the finalized block are gone.
+	for relay_parent in [finalized, ancestor] {
+		assert!(cache.validators(&relay_parent).is_none());
+		assert!(cache.session_index_for_child(&relay_parent).is_none());
+	}
+	// Unfinalized ones are kept.
+	assert_eq!(cache.validators(&descendant), Some(&validators));
+	assert_eq!(cache.session_index_for_child(&descendant), Some(&1));
+	// And so are session-keyed ones.
+	assert_eq!(cache.minimum_backing_votes(1), Some(2));
+}
+
+#[test]
+fn cache_evicts_oldest_entries_beyond_capacity() {
+	let mut cache = cache::RequestResultCache::new(2);
+	let (first, second, third) = ([1; 32].into(), [2; 32].into(), [3; 32].into());
+	let validators = vec![sp_keyring::Sr25519Keyring::Alice.public().into()];
+
+	for relay_parent in [first, second, third] {
+		cache.cache_validators(relay_parent, validators.clone());
+	}
+
+	assert!(cache.validators(&first).is_none());
+	assert_eq!(cache.validators(&second), Some(&validators));
+	assert_eq!(cache.validators(&third), Some(&validators));
+	assert_eq!(cache.take_evictions(), 1);
+	assert_eq!(cache.take_evictions(), 0);
+
+	// Overwriting an entry that is still cached doesn't evict anything.
+	cache.cache_validators(third, validators.clone());
+	assert_eq!(cache.take_evictions(), 0);
+}
+
 #[test]
 fn requests_validator_groups() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
@@ -364,8 +490,12 @@ fn requests_validator_groups() {
 	let relay_parent = [1; 32].into();
 	let spawner = sp_core::testing::TaskExecutor::new();
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -384,6 +514,45 @@ fn requests_validator_groups() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn requests_validators_and_groups() {
+	let subsystem_client = Arc::new(MockSubsystemClient::default());
+	let relay_parent = [1; 32].into();
+	let spawner = sp_core::testing::TaskExecutor::new();
+
+	let mut subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
+
+	let (tx, rx) = oneshot::channel();
+	subsystem.spawn_request(relay_parent, Request::ValidatorsAndGroups(tx));
+	futures::executor::block_on(subsystem.poll_requests());
+
+	let (validators, (groups, _)) = futures::executor::block_on(rx).unwrap().unwrap();
+	assert_eq!(validators, subsystem_client.validators);
+	assert_eq!(groups, subsystem_client.validator_groups);
+
+	// Both components are cached separately.
+	assert_eq!(
+		subsystem.requests_cache.validators(&relay_parent),
+		Some(&subsystem_client.validators)
+	);
+	assert_eq!(
+		subsystem.requests_cache.validator_groups(&relay_parent).map(|(groups, _)| groups),
+		Some(&subsystem_client.validator_groups)
+	);
+
+	// And a subsequent combined request is served from the cache.
+	let (tx, rx) = oneshot::channel();
+	subsystem.spawn_request(relay_parent, Request::ValidatorsAndGroups(tx));
+	assert!(subsystem.active_requests.is_empty());
+	assert_eq!(futures::executor::block_on(rx).unwrap().unwrap().0, subsystem_client.validators);
+	assert_eq!(*subsystem_client.validators_requests.lock().unwrap(), 1);
+}
+
 #[test]
 fn requests_availability_cores() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
@@ -391,8 +560,12 @@ fn requests_availability_cores() {
 	let relay_parent = [1; 32].into();
 	let spawner = sp_core::testing::TaskExecutor::new();
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -411,6 +584,74 @@ fn requests_availability_cores() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn requests_cores_and_claim_queue() {
+	let mut subsystem_client = MockSubsystemClient::default();
+	subsystem_client.availability_cores = vec![CoreState::Free];
+	subsystem_client.claim_queue =
+		BTreeMap::from([(CoreIndex(0), VecDeque::from([ParaId::from(1_u32)]))]);
+	let subsystem_client = Arc::new(subsystem_client);
+	let relay_parent = [1; 32].into();
+	let spawner = sp_core::testing::TaskExecutor::new();
+
+	let mut subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
+
+	let (tx, rx) = oneshot::channel();
+	subsystem.spawn_request(relay_parent, Request::CoresAndClaimQueue(tx));
+	futures::executor::block_on(subsystem.poll_requests());
+
+	let (cores, claim_queue) = futures::executor::block_on(rx).unwrap().unwrap();
+	assert_eq!(cores, subsystem_client.availability_cores);
+	assert_eq!(claim_queue, subsystem_client.claim_queue);
+
+	// Both components are cached separately.
+	assert_eq!(
+		subsystem.requests_cache.availability_cores(&relay_parent),
+		Some(&subsystem_client.availability_cores)
+	);
+	assert_eq!(
+		subsystem.requests_cache.claim_queue(&relay_parent),
+		Some(&subsystem_client.claim_queue)
+	);
+}
+
+#[test]
+fn unsupported_requests_are_counted_apart_from_failures() {
+	use polkadot_node_metrics::metrics::{prometheus::Registry, Metrics as _};
+
+	let subsystem_client =
+		Arc::new(MockSubsystemClient { api_version: Some(1), ..Default::default() });
+	let relay_parent = [1; 32].into();
+	let spawner = sp_core::testing::TaskExecutor::new();
+	let metrics = Metrics::try_register(&Registry::new()).unwrap();
+
+	let mut subsystem = RuntimeApiSubsystem::new(
+		subsystem_client,
+		metrics.clone(),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
+
+	let (tx, rx) = oneshot::channel();
+	subsystem.spawn_request(relay_parent, Request::ClaimQueue(tx));
+	futures::executor::block_on(subsystem.poll_requests());
+
+	assert!(matches!(
+		futures::executor::block_on(rx).unwrap(),
+		Err(RuntimeApiError::NotSupported { runtime_api_name: "claim_queue" })
+	));
+	let requests = |label: &str| {
+		metrics.0.as_ref().unwrap().chain_api_requests.with_label_values(&[label]).get()
+	};
+	assert_eq!(requests("not_supported"), 1);
+	assert_eq!(requests("failed"), 0);
+}
+
 #[test]
 fn requests_persisted_validation_data() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
@@ -423,8 +664,12 @@ fn requests_persisted_validation_data() {
 	subsystem_client.validation_data.insert(para_a, Default::default());
 	let subsystem_client = Arc::new(subsystem_client);
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -476,8 +721,12 @@ fn requests_assumed_validation_data() {
 	subsystem_client.validation_data.insert(para_b, Default::default());
 	let subsystem_client = Arc::new(subsystem_client);
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -526,8 +775,12 @@ fn requests_check_validation_outputs() {
 
 	let subsystem_client = Arc::new(subsystem_client);
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -572,8 +825,12 @@ fn requests_session_index_for_child() {
 	let relay_parent = [1; 32].into();
 	let spawner = sp_core::testing::TaskExecutor::new();
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -620,8 +877,12 @@ fn requests_session_info() {
 
 	let relay_parent = [1; 32].into();
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -643,6 +904,98 @@ fn requests_session_info() {
 	futures::executor::block_on(future::join(subsystem_task, test_task));
 }
 
+#[test]
+fn missing_session_info_is_cached() {
+	let subsystem_client = Arc::new(MockSubsystemClient::default());
+	let spawner = sp_core::testing::TaskExecutor::new();
+	let mut subsystem = RuntimeApiSubsystem::new(
+		subsystem_client,
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
+
+	let relay_parent = [1; 32].into();
+	subsystem.store_cache(RequestResult::SessionIndexForChild(relay_parent, 5));
+
+	// The info of a future session can still become available.
+	subsystem.store_cache(RequestResult::SessionInfo(relay_parent, 6, None));
+	let (tx, _rx) = oneshot::channel();
+	subsystem.spawn_request(relay_parent, Request::SessionInfo(6, tx));
+	assert_eq!(subsystem.active_requests.len(), 1);
+
+	// The info of a past session is known to be missing.
+	subsystem.store_cache(RequestResult::SessionInfo(relay_parent, 1, None));
+	let (tx, rx) = oneshot::channel();
+	subsystem.spawn_request(relay_parent, Request::SessionInfo(1, tx));
+	assert_eq!(subsystem.active_requests.len(), 1);
+	assert_eq!(futures::executor::block_on(rx).unwrap().unwrap(), None);
+}
+
+#[test]
+fn persisted_session_info_is_served_after_restart() {
+	let dir = tempfile::tempdir().unwrap();
+	let relay_parent = [1; 32].into();
+	let session_index = 1;
+
+	let mut subsystem = RuntimeApiSubsystem::new(
+		Arc::new(MockSubsystemClient::default()),
+		Metrics(None),
+		SpawnGlue(TaskExecutor::new()),
+		DEFAULT_CACHE_CAP,
+	)
+	.with_session_cache_dir(dir.path().to_path_buf());
+	// Write the results in place of the blocking task `store_cache` spawns, so that they are on
+	// disk before the restart.
+	subsystem.requests_cache.cache_session_info(session_index, dummy_session_info());
+	subsystem.requests_cache.take_session_writes().unwrap().write();
+	assert!(subsystem.requests_cache.take_session_writes().is_none());
+	drop(subsystem);
+
+	// The restarted subsystem serves the info from disk, without calling into the runtime, which
+	// would not even know the session.
+	let mut subsystem = RuntimeApiSubsystem::new(
+		Arc::new(MockSubsystemClient::default()),
+		Metrics(None),
+		SpawnGlue(TaskExecutor::new()),
+		DEFAULT_CACHE_CAP,
+	)
+	.with_session_cache_dir(dir.path().to_path_buf());
+	let (tx, rx) = oneshot::channel();
+	subsystem.spawn_request(relay_parent, Request::SessionInfo(session_index, tx));
+	assert!(subsystem.active_requests.is_empty());
+	assert_eq!(futures::executor::block_on(rx).unwrap().unwrap(), Some(dummy_session_info()));
+
+	// Without the directory, the cache starts out empty.
+	let mut subsystem = RuntimeApiSubsystem::new(
+		Arc::new(MockSubsystemClient::default()),
+		Metrics(None),
+		SpawnGlue(TaskExecutor::new()),
+		DEFAULT_CACHE_CAP,
+	);
+	let (tx, _rx) = oneshot::channel();
+	subsystem.spawn_request(relay_parent, Request::SessionInfo(session_index, tx));
+	assert_eq!(subsystem.active_requests.len(), 1);
+}
+
+#[test]
+fn persisted_session_results_are_pruned_on_write() {
+	let dir = tempfile::tempdir().unwrap();
+	let mut cache = cache::RequestResultCache::new(2);
+	cache.persist_sessions_in(dir.path().to_path_buf());
+
+	for session_index in 1..=3 {
+		cache.cache_session_info(session_index, dummy_session_info());
+		cache.take_session_writes().unwrap().write();
+	}
+
+	// Only the files of the two most recent sessions are left.
+	let session_info_dir = dir.path().join("session_info");
+	assert!(!session_info_dir.join("1").exists());
+	assert!(session_info_dir.join("2").exists());
+	assert!(session_info_dir.join("3").exists());
+}
+
 #[test]
 fn requests_validation_code() {
 	let (ctx, mut ctx_handle) = make_subsystem_context(TaskExecutor::new());
@@ -657,8 +1010,12 @@ fn requests_validation_code() {
 	subsystem_client.validation_code.insert(para_a, validation_code.clone());
 	let subsystem_client = Arc::new(subsystem_client);
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -707,8 +1064,12 @@ fn requests_candidate_pending_availability() {
 		.insert(para_a, candidate_receipt.clone());
 	let subsystem_client = Arc::new(subsystem_client);
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -750,8 +1111,12 @@ fn requests_candidate_events() {
 	let relay_parent = [1; 32].into();
 	let spawner = sp_core::testing::TaskExecutor::new();
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -791,8 +1156,12 @@ fn requests_dmq_contents() {
 		subsystem_client
 	});
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -845,8 +1214,12 @@ fn requests_inbound_hrmp_channels_contents() {
 		subsystem_client
 	});
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -894,8 +1267,12 @@ fn requests_validation_code_by_hash() {
 		(Arc::new(subsystem_client), validation_code)
 	};
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 
 	let relay_parent = [1; 32].into();
@@ -928,8 +1305,12 @@ fn multiple_requests_in_parallel_are_working() {
 	let spawner = sp_core::testing::TaskExecutor::new();
 	let mutex = subsystem_client.availability_cores_wait.clone();
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		// Make all requests block until we release this mutex.
@@ -991,8 +1372,12 @@ fn requests_babe_epoch() {
 	let relay_parent = [1; 32].into();
 	let spawner = sp_core::testing::TaskExecutor::new();
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -1016,8 +1401,12 @@ fn requests_submit_pvf_check_statement() {
 	let spawner = sp_core::testing::TaskExecutor::new();
 	let subsystem_client = Arc::new(MockSubsystemClient::default());
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 
 	let relay_parent = [1; 32].into();
@@ -1081,8 +1470,12 @@ fn requests_pvfs_require_precheck() {
 		subsystem_client
 	});
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 
 	let relay_parent = [1; 32].into();
@@ -1116,8 +1509,12 @@ fn requests_validation_code_hash() {
 	subsystem_client.validation_code_hash.insert(para_a, validation_code_hash);
 	let subsystem_client = Arc::new(subsystem_client);
 
-	let subsystem =
-		RuntimeApiSubsystem::new(subsystem_client.clone(), Metrics(None), SpawnGlue(spawner));
+	let subsystem = RuntimeApiSubsystem::new(
+		subsystem_client.clone(),
+		Metrics(None),
+		SpawnGlue(spawner),
+		DEFAULT_CACHE_CAP,
+	);
 	let subsystem_task = run(ctx, subsystem).map(|x| x.unwrap());
 	let test_task = async move {
 		let (tx, rx) = oneshot::channel();
@@ -285,6 +285,7 @@ where
 			runtime_client.clone(),
 			Metrics::register(registry)?,
 			spawner.clone(),
+			polkadot_node_core_runtime_api::DEFAULT_CACHE_CAP,
 		))
 		.statement_distribution(StatementDistributionSubsystem::new(
 			keystore.clone(),
@@ -454,6 +455,7 @@ where
 			runtime_client.clone(),
 			Metrics::register(registry)?,
 			spawner.clone(),
+			polkadot_node_core_runtime_api::DEFAULT_CACHE_CAP,
 		))
 		.statement_distribution(DummySubsystem)
 		.approval_distribution(DummySubsystem)
@@ -638,6 +638,10 @@ pub enum RuntimeApiRequest {
 	Validators(RuntimeApiSender<Vec<ValidatorId>>),
 	/// Get the validator groups and group rotation info.
 	ValidatorGroups(RuntimeApiSender<(Vec<Vec<ValidatorIndex>>, GroupRotationInfo)>),
+	/// Get both the current validator set and the validator groups with group rotation info.
+	ValidatorsAndGroups(
+		RuntimeApiSender<(Vec<ValidatorId>, (Vec<Vec<ValidatorIndex>>, GroupRotationInfo))>,
+	),
 	/// Get information on all availability cores.
 	AvailabilityCores(RuntimeApiSender<Vec<CoreState>>),
 	/// Get the persisted validation data for a particular para, taking the given
@@ -739,6 +743,9 @@ pub enum RuntimeApiRequest {
 	/// Fetch the `ClaimQueue` from scheduler pallet
 	/// `V11`
 	ClaimQueue(RuntimeApiSender<BTreeMap<CoreIndex, VecDeque<ParaId>>>),
+	/// Get both the information on all availability cores and the `ClaimQueue`
+	/// `V11`
+	CoresAndClaimQueue(RuntimeApiSender<(Vec<CoreState>, BTreeMap<CoreIndex, VecDeque<ParaId>>)>),
 	/// Get the candidates pending availability for a particular parachain
 	/// `V11`
 	CandidatesPendingAvailability(ParaId, RuntimeApiSender<Vec<CommittedCandidateReceipt>>),
@@ -342,6 +342,9 @@ pub enum XcmpMessageFormat {
 	/// One or more channel control signals; these should be interpreted immediately upon receipt
 	/// from the relay-chain.
 	Signals,
+	/// Encoded `VersionedXcm` messages, all concatenated and prefixed with a little-endian CRC32
+	/// checksum of the concatenated messages.
+	ChecksummedVersionedXcm,
 }
 
 /// Something that should be called for each batch of messages received over XCMP.
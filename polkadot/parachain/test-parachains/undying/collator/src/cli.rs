@@ -51,6 +51,15 @@ pub struct ExportGenesisHeadCommand {
 	/// we compute per block.
 	#[arg(long, default_value_t = 1)]
 	pub pvf_complexity: u32,
+
+	/// Export one genesis state per `<pov_size> <pvf_complexity>` pair listed in this file, one
+	/// pair per line, instead of a single one for `--pov-size` and `--pvf-complexity`.
+	#[arg(long, requires = "output_dir", conflicts_with = "output")]
+	pub params_file: Option<PathBuf>,
+
+	/// Directory to write the genesis states exported for `--params-file` to.
+	#[arg(long, requires = "params_file")]
+	pub output_dir: Option<PathBuf>,
 }
 
 /// Command for exporting the genesis wasm file.
@@ -81,6 +90,11 @@ pub struct RunCmd {
 	/// we compute per block.
 	#[arg(long, default_value_t = 1)]
 	pub pvf_complexity: u32,
+
+	/// Submit collations at most once every this many milliseconds, skipping the relay parents
+	/// seen in between. By default a collation is built for every relay parent.
+	#[arg(long)]
+	pub collation_interval_ms: Option<u64>,
 }
 
 #[allow(missing_docs)]
@@ -31,7 +31,7 @@ use std::{
 		atomic::{AtomicU32, Ordering},
 		Arc, Mutex,
 	},
-	time::Duration,
+	time::{Duration, Instant},
 };
 use test_parachain_undying::{
 	execute, hash_state, BlockData, GraveyardState, HeadData, StateMismatch,
@@ -301,6 +301,36 @@ impl Collator {
 		})
 	}
 
+	/// Create a collation function that submits collations at most once every `interval`.
+	///
+	/// Wraps the function returned by [`Self::create_collation_function`]. Relay parents seen
+	/// before `interval` has elapsed since the previous collation are skipped, so that collations
+	/// are never built for a relay parent that may be stale by the time they are submitted.
+	pub fn create_paced_collation_function(
+		&self,
+		spawner: impl SpawnNamed + Clone + 'static,
+		interval: Duration,
+	) -> CollatorFn {
+		use futures::FutureExt as _;
+
+		let collation_function = self.create_collation_function(spawner);
+		let next_collation = Arc::new(Mutex::new(Instant::now()));
+
+		Box::new(move |relay_parent, validation_data| {
+			{
+				let mut next_collation = next_collation.lock().unwrap();
+				let now = Instant::now();
+				if now < *next_collation {
+					log::trace!("Skipping relay parent {:?}, collating too early", relay_parent);
+					return async { None }.boxed()
+				}
+				*next_collation = now + interval;
+			}
+
+			collation_function(relay_parent, validation_data)
+		})
+	}
+
 	/// Wait until `blocks` are built and enacted.
 	pub async fn wait_for_blocks(&self, blocks: u64) {
 		let start_block = self.state.lock().unwrap().best_block;
@@ -396,6 +426,34 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn paced_collator_skips_relay_parents() {
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let collator = Collator::new(1_000, 1);
+		let collate = |collation_function: &CollatorFn, number| {
+			let parent_head =
+				collator.state.lock().unwrap().number_to_head.get(&number).unwrap().clone();
+			let validation_data = PersistedValidationData {
+				parent_head: parent_head.encode().into(),
+				..Default::default()
+			};
+			block_on(collation_function(Default::default(), &validation_data))
+		};
+
+		// the first relay parent is collated on, the next ones are skipped until the interval
+		// has elapsed.
+		let collation_function = collator
+			.create_paced_collation_function(spawner.clone(), Duration::from_secs(3600));
+		assert!(collate(&collation_function, 0).is_some());
+		assert!(collate(&collation_function, 1).is_none());
+		assert!(collate(&collation_function, 1).is_none());
+
+		// without an interval, every relay parent is collated on.
+		let collation_function = collator.create_paced_collation_function(spawner, Duration::ZERO);
+		assert!(collate(&collation_function, 1).is_some());
+		assert!(collate(&collation_function, 2).is_some());
+	}
+
 	#[test]
 	fn advance_to_state_when_parent_head_is_missing() {
 		let collator = Collator::new(1_000, 1);
@@ -25,6 +25,7 @@ use sp_core::hexdisplay::HexDisplay;
 use std::{
 	fs,
 	io::{self, Write},
+	time::Duration,
 };
 use test_parachain_undying_collator::Collator;
 
@@ -38,15 +39,24 @@ fn main() -> Result<()> {
 		Some(cli::Subcommand::ExportGenesisState(params)) => {
 			// `pov_size` and `pvf_complexity` need to match the ones that we start the collator
 			// with.
-			let collator = Collator::new(params.pov_size, params.pvf_complexity);
-
-			let output_buf =
-				format!("0x{:?}", HexDisplay::from(&collator.genesis_head())).into_bytes();
-
-			if let Some(output) = params.output {
-				std::fs::write(output, output_buf)?;
+			if let (Some(params_file), Some(output_dir)) = (params.params_file, params.output_dir)
+			{
+				fs::create_dir_all(&output_dir)?;
+				for (pov_size, pvf_complexity) in
+					parse_genesis_params(&fs::read_to_string(params_file)?)?
+				{
+					let output =
+						output_dir.join(format!("genesis-state-{}-{}", pov_size, pvf_complexity));
+					fs::write(output, genesis_state(pov_size, pvf_complexity))?;
+				}
 			} else {
-				std::io::stdout().write_all(&output_buf)?;
+				let output_buf = genesis_state(params.pov_size, params.pvf_complexity);
+
+				if let Some(output) = params.output {
+					std::fs::write(output, output_buf)?;
+				} else {
+					std::io::stdout().write_all(&output_buf)?;
+				}
 			}
 
 			Ok::<_, Error>(())
@@ -118,11 +128,21 @@ fn main() -> Result<()> {
 				log::info!("Genesis state: {}", genesis_head_hex);
 				log::info!("Validation code: {}", validation_code_hex);
 
+				let spawner = full_node.task_manager.spawn_handle();
+				let collation_function = match cli.run.collation_interval_ms {
+					Some(interval_ms) => {
+						log::info!("Submitting collations every {} ms", interval_ms);
+						collator.create_paced_collation_function(
+							spawner,
+							Duration::from_millis(interval_ms),
+						)
+					},
+					None => collator.create_collation_function(spawner),
+				};
+
 				let config = CollationGenerationConfig {
 					key: collator.collator_key(),
-					collator: Some(
-						collator.create_collation_function(full_node.task_manager.spawn_handle()),
-					),
+					collator: Some(collation_function),
 					para_id,
 				};
 				overseer_handle
@@ -139,3 +159,31 @@ fn main() -> Result<()> {
 	}?;
 	Ok(())
 }
+
+/// Returns the hex encoded genesis state of a collator with the given parameters.
+fn genesis_state(pov_size: usize, pvf_complexity: u32) -> Vec<u8> {
+	let collator = Collator::new(pov_size, pvf_complexity);
+	format!("0x{:?}", HexDisplay::from(&collator.genesis_head())).into_bytes()
+}
+
+/// Parses a newline-delimited list of `<pov_size> <pvf_complexity>` pairs, separated by
+/// whitespace or a comma. Empty lines and lines starting with `#` are skipped.
+fn parse_genesis_params(input: &str) -> Result<Vec<(usize, u32)>> {
+	input
+		.lines()
+		.map(str::trim)
+		.filter(|line| !line.is_empty() && !line.starts_with('#'))
+		.map(|line| {
+			let invalid = || Error::Other(format!("Invalid genesis params line: {:?}", line));
+			let mut parts =
+				line.split(|c: char| c == ',' || c.is_whitespace()).filter(|p| !p.is_empty());
+			match (parts.next(), parts.next(), parts.next()) {
+				(Some(pov_size), Some(pvf_complexity), None) => Ok((
+					pov_size.parse().map_err(|_| invalid())?,
+					pvf_complexity.parse().map_err(|_| invalid())?,
+				)),
+				_ => Err(invalid()),
+			}
+		})
+		.collect()
+}
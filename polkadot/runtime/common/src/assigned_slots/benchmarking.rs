@@ -152,6 +152,55 @@ mod benchmarks {
 		assert_eq!(MaxTemporarySlots::<T>::get(), u32::MAX);
 	}
 
+	#[benchmark]
+	fn extend_perm_parachain_slot() {
+		let para_id = ParaId::from(4_u32);
+		let caller = RawOrigin::Root;
+
+		let _ =
+			AssignedSlots::<T>::set_max_permanent_slots(frame_system::Origin::<T>::Root.into(), 10);
+		register_parachain::<T>(para_id);
+
+		assert_ok!(AssignedSlots::<T>::assign_perm_parachain_slot(caller.clone().into(), para_id));
+
+		let additional_periods =
+			LeasePeriodOf::<T>::from(T::PermanentSlotLeasePeriodLength::get());
+		let (period_begin, period_count) = PermanentSlots::<T>::get(para_id).unwrap();
+		#[extrinsic_call]
+		extend_perm_parachain_slot(caller, para_id, additional_periods);
+
+		assert_eq!(
+			PermanentSlots::<T>::get(para_id),
+			Some((period_begin, period_count + additional_periods))
+		);
+	}
+
+	#[benchmark]
+	fn promote_temp_to_perm() {
+		let para_id = ParaId::from(5_u32);
+		let caller = RawOrigin::Root;
+
+		let _ =
+			AssignedSlots::<T>::set_max_permanent_slots(frame_system::Origin::<T>::Root.into(), 10);
+		let _ =
+			AssignedSlots::<T>::set_max_temporary_slots(frame_system::Origin::<T>::Root.into(), 10);
+		register_parachain::<T>(para_id);
+
+		assert_ok!(AssignedSlots::<T>::assign_temp_parachain_slot(
+			caller.clone().into(),
+			para_id,
+			SlotLeasePeriodStart::Current,
+		));
+
+		let counter = PermanentSlotCount::<T>::get();
+		#[extrinsic_call]
+		promote_temp_to_perm(caller, para_id);
+
+		assert_eq!(TemporarySlots::<T>::get(para_id), None);
+		assert!(PermanentSlots::<T>::get(para_id).is_some());
+		assert_eq!(PermanentSlotCount::<T>::get(), counter + 1);
+	}
+
 	impl_benchmark_test_suite!(
 		AssignedSlots,
 		crate::assigned_slots::tests::new_test_ext(),
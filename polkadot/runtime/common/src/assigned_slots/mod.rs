@@ -77,6 +77,8 @@ pub trait WeightInfo {
 	fn unassign_parachain_slot() -> Weight;
 	fn set_max_permanent_slots() -> Weight;
 	fn set_max_temporary_slots() -> Weight;
+	fn extend_perm_parachain_slot() -> Weight;
+	fn promote_temp_to_perm() -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -96,6 +98,12 @@ impl WeightInfo for TestWeightInfo {
 	fn set_max_temporary_slots() -> Weight {
 		Weight::zero()
 	}
+	fn extend_perm_parachain_slot() -> Weight {
+		Weight::zero()
+	}
+	fn promote_temp_to_perm() -> Weight {
+		Weight::zero()
+	}
 }
 
 type BalanceOf<T> = <<<T as Config>::Leaser as Leaser<BlockNumberFor<T>>>::Currency as Currency<
@@ -208,6 +216,10 @@ pub mod pallet {
 		MaxPermanentSlotsChanged { slots: u32 },
 		/// The maximum number of temporary slots has been changed
 		MaxTemporarySlotsChanged { slots: u32 },
+		/// The lease of a permanent parachain slot was extended
+		PermanentSlotExtended { id: ParaId, additional_periods: LeasePeriodOf<T> },
+		/// A temporary parachain slot was promoted to a permanent parachain slot
+		TemporarySlotPromoted { id: ParaId },
 	}
 
 	#[pallet::error]
@@ -468,24 +480,106 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::MaxTemporarySlotsChanged { slots });
 			Ok(())
 		}
+
+		/// Extend the lease of a permanent parachain slot by `additional_periods` lease periods.
+		#[pallet::call_index(5)]
+		#[pallet::weight((<T as Config>::WeightInfo::extend_perm_parachain_slot(), DispatchClass::Operational))]
+		pub fn extend_perm_parachain_slot(
+			origin: OriginFor<T>,
+			id: ParaId,
+			additional_periods: LeasePeriodOf<T>,
+		) -> DispatchResult {
+			T::AssignSlotOrigin::ensure_origin(origin)?;
+
+			let (period_begin, period_count) =
+				PermanentSlots::<T>::get(id).ok_or(Error::<T>::SlotNotAssigned)?;
+			let manager = T::Registrar::manager_of(id).ok_or(Error::<T>::ParaDoesntExist)?;
+
+			// The extension starts where the current lease ends, or now if it already ended.
+			let extension_begin = period_begin
+				.saturating_add(period_count)
+				.max(Self::current_lease_period_index());
+
+			Self::configure_slot_lease(id, manager, extension_begin, additional_periods)
+				.map_err(|_| Error::<T>::CannotUpgrade)?;
+
+			let extension_end = extension_begin.saturating_add(additional_periods);
+			PermanentSlots::<T>::insert(
+				id,
+				(period_begin, extension_end.saturating_sub(period_begin)),
+			);
+
+			Self::deposit_event(Event::<T>::PermanentSlotExtended { id, additional_periods });
+			Ok(())
+		}
+
+		/// Promote a temporary parachain slot to a permanent one, replacing its temporary lease
+		/// with a permanent slot lease starting in the current lease period.
+		#[pallet::call_index(6)]
+		#[pallet::weight((<T as Config>::WeightInfo::promote_temp_to_perm(), DispatchClass::Operational))]
+		pub fn promote_temp_to_perm(origin: OriginFor<T>, id: ParaId) -> DispatchResult {
+			T::AssignSlotOrigin::ensure_origin(origin.clone())?;
+
+			ensure!(Self::has_temporary_slot(id), Error::<T>::SlotNotAssigned);
+
+			ensure!(
+				PermanentSlotCount::<T>::get() < MaxPermanentSlots::<T>::get(),
+				Error::<T>::MaxPermanentSlotsExceeded
+			);
+
+			let manager = T::Registrar::manager_of(id).ok_or(Error::<T>::ParaDoesntExist)?;
+
+			// Check & cache para status before we clear the lease
+			let is_parachain = Self::is_parachain(id);
+
+			// Replace the temporary slot lease by a permanent one
+			Self::clear_slot_leases(origin, id)?;
+
+			let current_lease_period: BlockNumberFor<T> = Self::current_lease_period_index();
+			Self::configure_slot_lease(
+				id,
+				manager,
+				current_lease_period,
+				T::PermanentSlotLeasePeriodLength::get().into(),
+			)
+			.map_err(|_| Error::<T>::CannotUpgrade)?;
+
+			TemporarySlots::<T>::remove(id);
+			TemporarySlotCount::<T>::mutate(|count| *count = count.saturating_sub(One::one()));
+			if is_parachain {
+				ActiveTemporarySlotCount::<T>::mutate(|active_count| {
+					*active_count = active_count.saturating_sub(One::one())
+				});
+			}
+
+			PermanentSlots::<T>::insert(
+				id,
+				(
+					current_lease_period,
+					LeasePeriodOf::<T>::from(T::PermanentSlotLeasePeriodLength::get()),
+				),
+			);
+			PermanentSlotCount::<T>::mutate(|count| count.saturating_inc());
+
+			Self::deposit_event(Event::<T>::TemporarySlotPromoted { id });
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	/// Allocate temporary slot leases up to `MaxTemporarySlotPerLeasePeriod` per lease period.
-	/// Beyond the already active temporary slot leases, this function will activate more leases
-	/// in the following order of preference:
-	/// - Assigned slots that didn't have a turn yet, though their `period_begin` has passed.
-	/// - Assigned slots that already had one (or more) turn(s): they will be considered for the
-	/// current slot lease if they weren't active in the preceding one, and will be ranked by
-	/// total number of lease (lower first), and then when they last a turn (older ones first).
-	/// If any remaining ex-aequo, we just take the para ID in ascending order as discriminator.
-	///
-	/// Assigned slots with a `period_begin` bigger than current lease period are not considered
-	/// (yet).
-	///
-	/// The function will call out to `Leaser::lease_out` to create the appropriate slot leases.
-	fn allocate_temporary_slot_leases(lease_period_index: LeasePeriodOf<T>) -> DispatchResult {
+	/// Returns the paras whose temporary slot would be allocated a lease at the start of lease
+	/// period `period`, in order of preference. See [`Self::allocate_temporary_slot_leases`] for
+	/// how that order is established.
+	pub fn next_temporary_slots_for_period(period: LeasePeriodOf<T>) -> Vec<ParaId> {
+		Self::select_temporary_slots(period).1.into_iter().map(|(para, _)| para).collect()
+	}
+
+	/// Returns the number of temporary slot leases active in `lease_period_index`, along with the
+	/// temporary slots that should be allocated a lease for it, in order of preference.
+	fn select_temporary_slots(
+		lease_period_index: LeasePeriodOf<T>,
+	) -> (u32, Vec<(ParaId, ParachainTemporarySlot<T::AccountId, LeasePeriodOf<T>>)>) {
 		let mut active_temp_slots = 0u32;
 		let mut pending_temp_slots = Vec::new();
 		TemporarySlots::<T>::iter().for_each(|(para, slot)| {
@@ -505,7 +599,7 @@ impl<T: Config> Pallet<T> {
 					},
 					None if slot.period_begin <= lease_period_index => {
 						// Slot hasn't had a lease yet
-						pending_temp_slots.insert(0, (para, slot));
+						pending_temp_slots.push((para, slot));
 					},
 					_ => {
 						// Slot not being considered for this lease period (will be for a subsequent one)
@@ -513,49 +607,64 @@ impl<T: Config> Pallet<T> {
 				}
 		});
 
-		let mut newly_created_lease = 0u32;
-		if active_temp_slots < T::MaxTemporarySlotPerLeasePeriod::get() &&
-			!pending_temp_slots.is_empty()
-		{
-			// Sort by lease_count, favoring slots that had no or less turns first
-			// (then by last_lease index, and then Para ID)
-			pending_temp_slots.sort_by(|a, b| {
-				a.1.lease_count
-					.cmp(&b.1.lease_count)
-					.then_with(|| a.1.last_lease.cmp(&b.1.last_lease))
-					.then_with(|| a.0.cmp(&b.0))
-			});
-
-			let slots_to_be_upgraded = pending_temp_slots.iter().take(
-				(T::MaxTemporarySlotPerLeasePeriod::get().saturating_sub(active_temp_slots))
-					as usize,
-			);
+		// Sort by lease_count, favoring slots that had no or less turns first
+		// (then by last_lease index, and then Para ID). The ordering doesn't depend on the
+		// storage iteration order, so the selection is fully deterministic.
+		pending_temp_slots.sort_by(|a, b| {
+			a.1.lease_count
+				.cmp(&b.1.lease_count)
+				.then_with(|| a.1.last_lease.cmp(&b.1.last_lease))
+				.then_with(|| a.0.cmp(&b.0))
+		});
+		pending_temp_slots.truncate(
+			T::MaxTemporarySlotPerLeasePeriod::get().saturating_sub(active_temp_slots) as usize,
+		);
 
-			for (id, temp_slot) in slots_to_be_upgraded {
-				TemporarySlots::<T>::try_mutate::<_, _, Error<T>, _>(id, |s| {
-					// Configure temp slot lease
-					Self::configure_slot_lease(
-						*id,
-						temp_slot.manager.clone(),
-						lease_period_index,
-						temp_slot.period_count,
-					)
-					.map_err(|_| Error::<T>::CannotUpgrade)?;
-
-					// Update temp slot lease info in storage
-					*s = Some(ParachainTemporarySlot {
-						manager: temp_slot.manager.clone(),
-						period_begin: temp_slot.period_begin,
-						period_count: temp_slot.period_count,
-						last_lease: Some(lease_period_index),
-						lease_count: temp_slot.lease_count + 1,
-					});
+		(active_temp_slots, pending_temp_slots)
+	}
 
-					newly_created_lease += 1;
+	/// Allocate temporary slot leases up to `MaxTemporarySlotPerLeasePeriod` per lease period.
+	/// Beyond the already active temporary slot leases, this function will activate more leases
+	/// in the following order of preference:
+	/// - Assigned slots that didn't have a turn yet, though their `period_begin` has passed.
+	/// - Assigned slots that already had one (or more) turn(s): they will be considered for the
+	/// current slot lease if they weren't active in the preceding one, and will be ranked by
+	/// total number of lease (lower first), and then when they last a turn (older ones first).
+	/// If any remaining ex-aequo, we just take the para ID in ascending order as discriminator.
+	///
+	/// Assigned slots with a `period_begin` bigger than current lease period are not considered
+	/// (yet).
+	///
+	/// The function will call out to `Leaser::lease_out` to create the appropriate slot leases.
+	fn allocate_temporary_slot_leases(lease_period_index: LeasePeriodOf<T>) -> DispatchResult {
+		let (active_temp_slots, slots_to_be_upgraded) =
+			Self::select_temporary_slots(lease_period_index);
 
-					Ok(())
-				})?;
-			}
+		let mut newly_created_lease = 0u32;
+		for (id, temp_slot) in slots_to_be_upgraded {
+			TemporarySlots::<T>::try_mutate::<_, _, Error<T>, _>(id, |s| {
+				// Configure temp slot lease
+				Self::configure_slot_lease(
+					id,
+					temp_slot.manager.clone(),
+					lease_period_index,
+					temp_slot.period_count,
+				)
+				.map_err(|_| Error::<T>::CannotUpgrade)?;
+
+				// Update temp slot lease info in storage
+				*s = Some(ParachainTemporarySlot {
+					manager: temp_slot.manager.clone(),
+					period_begin: temp_slot.period_begin,
+					period_count: temp_slot.period_count,
+					last_lease: Some(lease_period_index),
+					lease_count: temp_slot.lease_count + 1,
+				});
+
+				newly_created_lease += 1;
+
+				Ok(())
+			})?;
 		}
 
 		ActiveTemporarySlotCount::<T>::set(active_temp_slots + newly_created_lease);
@@ -590,6 +699,20 @@ impl<T: Config> Pallet<T> {
 		TemporarySlots::<T>::contains_key(id)
 	}
 
+	/// Returns the paras whose temporary slot lease is active in the current lease period.
+	pub fn active_temporary_slots() -> Vec<ParaId> {
+		let lease_period_index = Self::current_lease_period_index();
+		TemporarySlots::<T>::iter()
+			.filter(|(_, slot)| {
+				slot.last_lease.map_or(false, |last_lease| {
+					last_lease <= lease_period_index &&
+						lease_period_index < last_lease.saturating_add(slot.period_count)
+				})
+			})
+			.map(|(para, _)| para)
+			.collect()
+	}
+
 	/// Returns whether a para is currently a lease holding parachain.
 	fn is_parachain(id: ParaId) -> bool {
 		T::Registrar::is_parachain(id)
@@ -1333,6 +1456,97 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn active_temporary_slots_follows_rotation() {
+		new_test_ext().execute_with(|| {
+			// Block 1, Period 0
+			run_to_block(1);
+
+			for n in 0..=5 {
+				assert_ok!(TestRegistrar::<Test>::register(
+					n,
+					ParaId::from(n as u32),
+					dummy_head_data(),
+					dummy_validation_code()
+				));
+
+				assert_ok!(AssignedSlots::assign_temp_parachain_slot(
+					RuntimeOrigin::root(),
+					ParaId::from(n as u32),
+					if (n % 2).is_zero() {
+						SlotLeasePeriodStart::Current
+					} else {
+						SlotLeasePeriodStart::Next
+					}
+				));
+			}
+
+			let active_slots = || {
+				let mut slots = AssignedSlots::active_temporary_slots();
+				slots.sort();
+				slots
+			};
+
+			assert_eq!(active_slots(), vec![ParaId::from(0_u32), ParaId::from(2_u32)]);
+
+			// Block 6, Period 2: the first rotation kicks in
+			run_to_block(6);
+			assert_eq!(active_slots(), vec![ParaId::from(1_u32), ParaId::from(3_u32)]);
+
+			// Block 9, Period 3: leases started last period are still active
+			run_to_block(9);
+			assert_eq!(active_slots(), vec![ParaId::from(1_u32), ParaId::from(3_u32)]);
+
+			// Block 12, Period 4
+			run_to_block(12);
+			assert_eq!(active_slots(), vec![ParaId::from(4_u32), ParaId::from(5_u32)]);
+		});
+	}
+
+	#[test]
+	fn next_temporary_slots_for_period_prioritizes_starved_slots() {
+		new_test_ext().execute_with(|| {
+			run_to_block(1);
+
+			let temp_slot = |lease_count, last_lease| ParachainTemporarySlot {
+				manager: 1,
+				period_begin: 0,
+				period_count: 2,
+				last_lease,
+				lease_count,
+			};
+			TemporarySlots::<Test>::insert(ParaId::from(1_u32), temp_slot(1, Some(0)));
+			TemporarySlots::<Test>::insert(ParaId::from(2_u32), temp_slot(1, Some(2)));
+			TemporarySlots::<Test>::insert(ParaId::from(3_u32), temp_slot(1, Some(1)));
+			TemporarySlots::<Test>::insert(ParaId::from(4_u32), temp_slot(2, Some(0)));
+
+			// Only slots whose last lease is old enough are considered
+			assert_eq!(
+				AssignedSlots::next_temporary_slots_for_period(4),
+				vec![ParaId::from(1_u32), ParaId::from(4_u32)]
+			);
+
+			// Fewer turns first, then oldest `last_lease` first
+			assert_eq!(
+				AssignedSlots::next_temporary_slots_for_period(6),
+				vec![ParaId::from(1_u32), ParaId::from(3_u32)]
+			);
+
+			// Slots that never had a turn take precedence
+			TemporarySlots::<Test>::insert(ParaId::from(5_u32), temp_slot(0, None));
+			assert_eq!(
+				AssignedSlots::next_temporary_slots_for_period(6),
+				vec![ParaId::from(5_u32), ParaId::from(1_u32)]
+			);
+
+			// Active leases use up the available slots
+			assert_eq!(
+				AssignedSlots::next_temporary_slots_for_period(3),
+				vec![ParaId::from(5_u32)]
+			);
+		});
+	}
+
 	#[test]
 	fn unassign_slot_fails_for_unknown_para() {
 		new_test_ext().execute_with(|| {
@@ -1468,4 +1682,145 @@ mod tests {
 			assert_eq!(MaxTemporarySlots::<Test>::get(), 12);
 		});
 	}
+
+	#[test]
+	fn promote_temp_to_perm_fails_for_unassigned_slot() {
+		new_test_ext().execute_with(|| {
+			run_to_block(1);
+
+			assert_ok!(TestRegistrar::<Test>::register(
+				1,
+				ParaId::from(1_u32),
+				dummy_head_data(),
+				dummy_validation_code(),
+			));
+
+			assert_noop!(
+				AssignedSlots::promote_temp_to_perm(RuntimeOrigin::root(), ParaId::from(1_u32)),
+				Error::<Test>::SlotNotAssigned
+			);
+		});
+	}
+
+	#[test]
+	fn promote_temp_to_perm_fails_when_max_perm_slots_exceeded() {
+		new_test_ext().execute_with(|| {
+			run_to_block(1);
+
+			assert_ok!(TestRegistrar::<Test>::register(
+				1,
+				ParaId::from(1_u32),
+				dummy_head_data(),
+				dummy_validation_code(),
+			));
+
+			assert_ok!(AssignedSlots::assign_temp_parachain_slot(
+				RuntimeOrigin::root(),
+				ParaId::from(1_u32),
+				SlotLeasePeriodStart::Current
+			));
+
+			assert_ok!(AssignedSlots::set_max_permanent_slots(RuntimeOrigin::root(), 0));
+
+			assert_noop!(
+				AssignedSlots::promote_temp_to_perm(RuntimeOrigin::root(), ParaId::from(1_u32)),
+				Error::<Test>::MaxPermanentSlotsExceeded
+			);
+		});
+	}
+
+	#[test]
+	fn promote_temp_to_perm_succeeds() {
+		new_test_ext().execute_with(|| {
+			run_to_block(1);
+
+			assert_ok!(TestRegistrar::<Test>::register(
+				1,
+				ParaId::from(1_u32),
+				dummy_head_data(),
+				dummy_validation_code(),
+			));
+
+			assert_ok!(AssignedSlots::assign_temp_parachain_slot(
+				RuntimeOrigin::root(),
+				ParaId::from(1_u32),
+				SlotLeasePeriodStart::Current
+			));
+
+			assert_eq!(TestRegistrar::<Test>::is_parachain(ParaId::from(1_u32)), true);
+			assert_eq!(assigned_slots::ActiveTemporarySlotCount::<Test>::get(), 1);
+
+			assert_ok!(AssignedSlots::promote_temp_to_perm(
+				RuntimeOrigin::root(),
+				ParaId::from(1_u32),
+			));
+
+			assert_eq!(AssignedSlots::has_temporary_slot(ParaId::from(1_u32)), false);
+			assert_eq!(assigned_slots::TemporarySlotCount::<Test>::get(), 0);
+			assert_eq!(assigned_slots::ActiveTemporarySlotCount::<Test>::get(), 0);
+
+			assert_eq!(assigned_slots::PermanentSlotCount::<Test>::get(), 1);
+			assert_eq!(
+				assigned_slots::PermanentSlots::<Test>::get(ParaId::from(1_u32)),
+				Some((0, 3))
+			);
+			assert_eq!(Slots::already_leased(ParaId::from(1_u32), 0, 2), true);
+			assert_eq!(TestRegistrar::<Test>::is_parachain(ParaId::from(1_u32)), true);
+		});
+	}
+
+	#[test]
+	fn extend_perm_slot_fails_for_unassigned_slot() {
+		new_test_ext().execute_with(|| {
+			run_to_block(1);
+
+			assert_ok!(TestRegistrar::<Test>::register(
+				1,
+				ParaId::from(1_u32),
+				dummy_head_data(),
+				dummy_validation_code(),
+			));
+
+			assert_noop!(
+				AssignedSlots::extend_perm_parachain_slot(
+					RuntimeOrigin::root(),
+					ParaId::from(1_u32),
+					2,
+				),
+				Error::<Test>::SlotNotAssigned
+			);
+		});
+	}
+
+	#[test]
+	fn extend_perm_slot_succeeds() {
+		new_test_ext().execute_with(|| {
+			run_to_block(1);
+
+			assert_ok!(TestRegistrar::<Test>::register(
+				1,
+				ParaId::from(1_u32),
+				dummy_head_data(),
+				dummy_validation_code(),
+			));
+
+			assert_ok!(AssignedSlots::assign_perm_parachain_slot(
+				RuntimeOrigin::root(),
+				ParaId::from(1_u32),
+			));
+
+			assert_ok!(AssignedSlots::extend_perm_parachain_slot(
+				RuntimeOrigin::root(),
+				ParaId::from(1_u32),
+				2,
+			));
+
+			assert_eq!(
+				assigned_slots::PermanentSlots::<Test>::get(ParaId::from(1_u32)),
+				Some((0, 5))
+			);
+			assert_eq!(Slots::already_leased(ParaId::from(1_u32), 3, 4), true);
+			assert_eq!(Slots::already_leased(ParaId::from(1_u32), 5, 5), false);
+		});
+	}
 }
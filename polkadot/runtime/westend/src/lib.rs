@@ -157,7 +157,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 1_011_000,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 25,
+	transaction_version: 26,
 	state_version: 1,
 };
 
@@ -630,6 +630,7 @@ impl pallet_staking::Config for Runtime {
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
+	type RewardClaimDelay = ConstU32<0>;
 	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
 	type SessionInterface = Self;
 	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
@@ -1652,7 +1653,10 @@ pub mod migrations {
 	}
 
 	/// Unreleased migrations. Add new ones here:
-	pub type Unreleased = (pallet_staking::migrations::v15::MigrateV14ToV15<Runtime>,);
+	pub type Unreleased = (
+		pallet_staking::migrations::v15::MigrateV14ToV15<Runtime>,
+		pallet_staking::migrations::v16::MigrateV15ToV16<Runtime>,
+	);
 }
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -2266,6 +2270,58 @@ sp_api::impl_runtime_apis! {
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn simulate_entry_threshold(new_validator_count: u32) -> Balance {
+			Staking::simulate_entry_threshold(new_validator_count)
+		}
+
+		fn era_reward_per_point(era: sp_staking::EraIndex) -> Option<Balance> {
+			Staking::era_reward_per_point(era)
+		}
+
+		fn is_page_claimable(era: sp_staking::EraIndex, account: AccountId, page: sp_staking::Page) -> bool {
+			Staking::is_page_claimable(&account, era, page)
+		}
+
+		fn era_reward_points(era: sp_staking::EraIndex) -> (u32, Vec<(AccountId, u32)>) {
+			Staking::api_era_reward_points(era)
+		}
+
+		fn unlocking_schedule(stash: AccountId) -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::unlocking_schedule(&stash)
+		}
+
+		fn estimate_pending_reward(nominator: AccountId, era: sp_staking::EraIndex) -> Option<Balance> {
+			Staking::estimate_pending_reward(&nominator, era)
+		}
+
+		fn active_era_validators() -> Vec<(AccountId, Balance, Balance)> {
+			Staking::active_era_validators()
+		}
+
+		fn unclaimed_payout_pages(account: AccountId) -> Vec<(sp_staking::EraIndex, sp_staking::Page)> {
+			Staking::unclaimed_payout_pages(&account)
+		}
+
+		fn is_exposed_in_active_era(stash: AccountId) -> bool {
+			Staking::is_exposed_in_active_era(&stash)
+		}
+
+		fn projected_era_payout(total_staked: Balance, total_issuance: Balance, era_duration_millis: u64) -> (Balance, Balance) {
+			Staking::projected_era_payout(total_staked, total_issuance, era_duration_millis)
+		}
+
+		fn can_chill_other(caller: AccountId, stash: AccountId) -> bool {
+			Staking::can_chill_other(&caller, &stash)
+		}
+
+		fn eras_total_stake_history() -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::eras_total_stake_history()
+		}
+
+		fn nominator_reward_share(era: sp_staking::EraIndex, validator: AccountId, nominator: AccountId) -> Option<Perbill> {
+			Staking::nominator_reward_share(era, &validator, &nominator)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -821,6 +821,51 @@ fn successful_execution_gives_ok() {
 	});
 }
 
+#[test]
+fn remaining_block_weight_drops_after_applying_extrinsic() {
+	let mut t = new_test_ext(compact_code_unwrap());
+	t.insert(
+		<frame_system::Account<Runtime>>::hashed_key_for(alice()),
+		AccountInfo::<<Runtime as frame_system::Config>::Nonce, _> {
+			providers: 1,
+			data: (111 * DOLLARS, 0u128, 0u128, 1u128 << 127),
+			..Default::default()
+		}
+		.encode(),
+	);
+	t.insert(
+		<pallet_balances::TotalIssuance<Runtime>>::hashed_key().to_vec(),
+		(111 * DOLLARS).encode(),
+	);
+	t.insert(<frame_system::BlockHash<Runtime>>::hashed_key_for(0), vec![0u8; 32]);
+
+	let r = executor_call(&mut t, "Core_initialize_block", &vec![].and(&from_block_number(1u32))).0;
+	assert!(r.is_ok());
+
+	let remaining_block_weight = |t: &mut TestExternalities<_>| {
+		let r = executor_call(t, "BlockBuilder_remaining_block_weight", &[]).0.unwrap();
+		Weight::decode(&mut &r[..]).unwrap()
+	};
+	let normal_block_weight = |t: &mut TestExternalities<_>| {
+		t.execute_with(|| *System::block_weight().get(DispatchClass::Normal))
+	};
+	let before = remaining_block_weight(&mut t);
+	let consumed_before = normal_block_weight(&mut t);
+
+	let r = executor_call(&mut t, "BlockBuilder_apply_extrinsic", &vec![].and(&xt()))
+		.0
+		.unwrap();
+	ApplyExtrinsicResult::decode(&mut &r[..])
+		.unwrap()
+		.expect("Extrinsic could not be applied")
+		.expect("Extrinsic failed");
+
+	let after = remaining_block_weight(&mut t);
+	let consumed = normal_block_weight(&mut t).saturating_sub(consumed_before);
+	assert!(consumed.any_gt(Weight::zero()));
+	assert_eq!(before.saturating_sub(after), consumed);
+}
+
 #[test]
 fn should_import_block_with_test_client() {
 	use node_testing::client::{
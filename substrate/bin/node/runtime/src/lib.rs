@@ -94,7 +94,10 @@ use sp_runtime::{
 		self, AccountIdConversion, BlakeTwo256, Block as BlockT, Bounded, ConvertInto, NumberFor,
 		OpaqueKeys, SaturatedConversion, StaticLookup,
 	},
-	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity,
+		TransactionValidityError,
+	},
 	ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Percent, Permill, Perquintill,
 	RuntimeDebug,
 };
@@ -680,6 +683,7 @@ impl pallet_staking::Config for Runtime {
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
+	type RewardClaimDelay = ConstU32<0>;
 	/// A super-majority of the council can cancel the slash.
 	type AdminOrigin = EitherOfDiverse<
 		EnsureRoot<AccountId>,
@@ -2690,11 +2694,38 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(7)]
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
 		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
 			Executive::apply_extrinsic(extrinsic)
 		}
 
+		fn apply_extrinsics(
+			extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+		) -> Vec<ApplyExtrinsicResult> {
+			let mut results = Vec::with_capacity(extrinsics.len());
+			for extrinsic in extrinsics {
+				let result = Executive::apply_extrinsic(extrinsic);
+				let exhausted = matches!(
+					result,
+					Err(TransactionValidityError::Invalid(InvalidTransaction::ExhaustsResources))
+				);
+				results.push(result);
+				if exhausted {
+					break
+				}
+			}
+			results
+		}
+
+		fn remaining_block_weight() -> Weight {
+			let max = RuntimeBlockWeights::get()
+				.get(DispatchClass::Normal)
+				.max_total
+				.unwrap_or(RuntimeBlockWeights::get().max_block);
+			max.saturating_sub(*System::block_weight().get(DispatchClass::Normal))
+		}
+
 		fn finalize_block() -> <Block as BlockT>::Header {
 			Executive::finalize_block()
 		}
@@ -2795,6 +2826,58 @@ impl_runtime_apis! {
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn simulate_entry_threshold(new_validator_count: u32) -> Balance {
+			Staking::simulate_entry_threshold(new_validator_count)
+		}
+
+		fn era_reward_per_point(era: sp_staking::EraIndex) -> Option<Balance> {
+			Staking::era_reward_per_point(era)
+		}
+
+		fn is_page_claimable(era: sp_staking::EraIndex, account: AccountId, page: sp_staking::Page) -> bool {
+			Staking::is_page_claimable(&account, era, page)
+		}
+
+		fn era_reward_points(era: sp_staking::EraIndex) -> (u32, Vec<(AccountId, u32)>) {
+			Staking::api_era_reward_points(era)
+		}
+
+		fn unlocking_schedule(stash: AccountId) -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::unlocking_schedule(&stash)
+		}
+
+		fn estimate_pending_reward(nominator: AccountId, era: sp_staking::EraIndex) -> Option<Balance> {
+			Staking::estimate_pending_reward(&nominator, era)
+		}
+
+		fn active_era_validators() -> Vec<(AccountId, Balance, Balance)> {
+			Staking::active_era_validators()
+		}
+
+		fn unclaimed_payout_pages(account: AccountId) -> Vec<(sp_staking::EraIndex, sp_staking::Page)> {
+			Staking::unclaimed_payout_pages(&account)
+		}
+
+		fn is_exposed_in_active_era(stash: AccountId) -> bool {
+			Staking::is_exposed_in_active_era(&stash)
+		}
+
+		fn projected_era_payout(total_staked: Balance, total_issuance: Balance, era_duration_millis: u64) -> (Balance, Balance) {
+			Staking::projected_era_payout(total_staked, total_issuance, era_duration_millis)
+		}
+
+		fn can_chill_other(caller: AccountId, stash: AccountId) -> bool {
+			Staking::can_chill_other(&caller, &stash)
+		}
+
+		fn eras_total_stake_history() -> Vec<(sp_staking::EraIndex, Balance)> {
+			Staking::eras_total_stake_history()
+		}
+
+		fn nominator_reward_share(era: sp_staking::EraIndex, validator: AccountId, nominator: AccountId) -> Option<Perbill> {
+			Staking::nominator_reward_share(era, &validator, &nominator)
+		}
 	}
 
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
@@ -43,6 +43,7 @@ use std::marker::PhantomData;
 
 pub use sp_block_builder::BlockBuilder as BlockBuilderApi;
 use sp_trie::proof_size_extension::ProofSizeExt;
+use sp_weights::Weight;
 
 /// A builder for creating an instance of [`BlockBuilder`].
 pub struct BlockBuilderBuilder<'a, B, C> {
@@ -357,6 +358,17 @@ where
 			.map_err(|e| Error::Application(Box::new(e)))
 	}
 
+	/// The weight of the `Normal` dispatch class that is still available in the block.
+	///
+	/// Returns `None` if the runtime does not support this query.
+	pub fn remaining_block_weight(&self) -> Result<Option<Weight>, Error> {
+		if self.version < 7 {
+			return Ok(None)
+		}
+
+		self.api.remaining_block_weight(self.parent_hash).map(Some).map_err(Into::into)
+	}
+
 	/// Estimate the size of the block in the current state.
 	///
 	/// If `include_proof` is `true`, the estimated size of the storage proof will be added
@@ -377,9 +389,11 @@ mod tests {
 	use super::*;
 	use sp_blockchain::HeaderBackend;
 	use sp_core::Blake2Hasher;
+	use sp_runtime::Perbill;
 	use sp_state_machine::Backend;
 	use substrate_test_runtime_client::{
-		runtime::ExtrinsicBuilder, DefaultTestClientBuilderExt, TestClientBuilderExt,
+		runtime::{ExtrinsicBuilder, RuntimeBlockWeights},
+		DefaultTestClientBuilderExt, TestClientBuilderExt,
 	};
 
 	#[test]
@@ -459,4 +473,26 @@ mod tests {
 		assert!(proof_without_panic > proof_empty_block);
 		assert_eq!(proof_empty_block, proof_with_panic);
 	}
+
+	#[test]
+	fn remaining_block_weight_drops_after_push() {
+		let builder = substrate_test_runtime_client::TestClientBuilder::new();
+		let client = builder.build();
+		let genesis_hash = client.info().best_hash;
+
+		let mut block_builder = BlockBuilderBuilder::new(&client)
+			.on_parent_block(genesis_hash)
+			.with_parent_block_number(0)
+			.build()
+			.unwrap();
+
+		let ratio = Perbill::from_percent(10);
+		let before = block_builder.remaining_block_weight().unwrap().unwrap();
+		block_builder.push(ExtrinsicBuilder::new_fill_block(ratio).build()).unwrap();
+		let after = block_builder.remaining_block_weight().unwrap().unwrap();
+
+		// The remaining weight drops by at least the weight of the call.
+		let filled = ratio * RuntimeBlockWeights::get().max_block;
+		assert!(before.saturating_sub(after).all_gte(filled));
+	}
 }
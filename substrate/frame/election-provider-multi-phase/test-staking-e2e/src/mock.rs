@@ -304,6 +304,7 @@ impl pallet_staking::Config for Runtime {
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
+	type RewardClaimDelay = ();
 	type AdminOrigin = EnsureRoot<AccountId>; // root can cancel slashes
 	type SessionInterface = Self;
 	type EraPayout = ();
@@ -36,6 +36,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+
 #[cfg(feature = "try-runtime")]
 use sp_runtime::TryRuntimeError;
 
@@ -45,7 +47,7 @@ pub use weights::*;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::*;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
 	use frame_system::pallet_prelude::*;
 
 	/// The config trait of the pallet. You can basically do anything with the config trait that you
@@ -102,7 +104,7 @@ pub mod pallet {
 		}
 	}
 
-	const STORAGE_VERSION: frame_support::traits::StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: frame_support::traits::StorageVersion = StorageVersion::new(2);
 
 	/// The pallet struct. There's nothing special to FRAME about this; it can implement functions
 	/// in an impl blocks, traits and so on.
@@ -117,6 +119,16 @@ pub mod pallet {
 
 	// first, we showcase all the possible storage types, with most of their details.
 
+	/// The value stored in [`Foo`]. Up to storage version 1, [`Foo`] held a plain `u32`; see
+	/// [`crate::migrations::v2`] for how it was migrated to this struct.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct FooInfo {
+		/// The value last set.
+		pub current: u32,
+		/// The value it replaced, if any.
+		pub previous: Option<u32>,
+	}
+
 	/// A storage value. We mark this as unbounded, alter its prefix, and define a custom storage
 	/// getter for it.
 	///
@@ -125,7 +137,7 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::unbounded] // optional
 	#[pallet::storage_prefix = "OtherFoo"] // optional
-	pub type Foo<T> = StorageValue<Value = u32>;
+	pub type Foo<T> = StorageValue<Value = FooInfo>;
 
 	#[pallet::type_value]
 	pub fn DefaultForFoo() -> u32 {
@@ -195,7 +207,7 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
 		fn build(&self) {
-			Foo::<T>::put(self.foo);
+			Foo::<T>::put(FooInfo { current: self.foo, previous: None });
 		}
 	}
 
@@ -214,7 +226,10 @@ pub mod pallet {
 			new_foo: u32,
 			#[pallet::compact] _other_compact: u128,
 		) -> DispatchResult {
-			Foo::<T>::set(Some(new_foo));
+			Foo::<T>::mutate(|foo| {
+				let previous = foo.as_ref().map(|foo| foo.current);
+				*foo = Some(FooInfo { current: new_foo, previous });
+			});
 
 			Ok(())
 		}
@@ -271,18 +286,21 @@ pub mod pallet {
 			unimplemented!()
 		}
 
+		/// Migrations can be executed from here, or passed to `Executive` by the runtime. Wrapping
+		/// them in a [`frame_support::migrations::VersionedMigration`] makes them safe to keep
+		/// around: they only run while the on-chain storage version matches.
 		fn on_runtime_upgrade() -> Weight {
-			unimplemented!()
+			<migrations::v2::MigrateV1ToV2<T> as OnRuntimeUpgrade>::on_runtime_upgrade()
 		}
 
 		#[cfg(feature = "try-runtime")]
 		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
-			unimplemented!()
+			<migrations::v2::MigrateV1ToV2<T> as OnRuntimeUpgrade>::pre_upgrade()
 		}
 
 		#[cfg(feature = "try-runtime")]
-		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
-			unimplemented!()
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			<migrations::v2::MigrateV1ToV2<T> as OnRuntimeUpgrade>::post_upgrade(state)
 		}
 
 		#[cfg(feature = "try-runtime")]
@@ -0,0 +1,91 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations of the kitchensink pallet.
+
+/// Migration from storage version 1 to 2, in which [`crate::Foo`] goes from a plain `u32` to a
+/// [`crate::FooInfo`].
+pub mod v2 {
+	use crate::{Config, Foo, FooInfo, Pallet};
+	use frame_support::{
+		storage_alias,
+		traits::{Get, UncheckedOnRuntimeUpgrade},
+		weights::Weight,
+	};
+
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	/// Storage items in the format of the previous storage version.
+	mod v1 {
+		use super::*;
+
+		/// V1 type of [`crate::Foo`]. Note that the alias is named after the storage prefix of
+		/// the item (`#[pallet::storage_prefix = "OtherFoo"]`), not after its type name.
+		#[storage_alias]
+		pub type OtherFoo<T: Config> = StorageValue<Pallet<T>, u32>;
+	}
+
+	/// Migrates [`crate::Foo`] from a `u32` to a [`crate::FooInfo`], without checking or
+	/// updating the storage version. Use [`MigrateV1ToV2`] instead.
+	pub struct VersionUncheckedMigrateV1ToV2<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV1ToV2<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			use codec::Encode;
+
+			Ok(v1::OtherFoo::<T>::get().encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			match v1::OtherFoo::<T>::take() {
+				Some(current) => {
+					Foo::<T>::put(FooInfo { current, previous: None });
+					T::DbWeight::get().reads_writes(1, 1)
+				},
+				None => T::DbWeight::get().reads(1),
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			use codec::Decode;
+			use frame_support::ensure;
+
+			let old_foo = Option::<u32>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade value of `Foo`")?;
+
+			ensure!(
+				Foo::<T>::get() == old_foo.map(|current| FooInfo { current, previous: None }),
+				"`Foo` was not migrated correctly"
+			);
+			Ok(())
+		}
+	}
+
+	/// [`VersionUncheckedMigrateV1ToV2`] wrapped in a
+	/// [`VersionedMigration`](frame_support::migrations::VersionedMigration), so that it only
+	/// runs when the on-chain storage version is 1, and bumps it to 2 afterwards.
+	pub type MigrateV1ToV2<T> = frame_support::migrations::VersionedMigration<
+		1,
+		2,
+		VersionUncheckedMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -18,7 +18,10 @@
 //! Tests for pallet-example-kitchensink.
 
 use crate::*;
-use frame_support::{assert_ok, derive_impl, parameter_types, traits::ConstU64};
+use frame_support::{
+	assert_ok, derive_impl, parameter_types,
+	traits::{ConstU64, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use sp_runtime::BuildStorage;
 // Reexport crate as its pallet name for construct_runtime.
 use crate as pallet_example_kitchensink;
@@ -94,10 +97,30 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 #[test]
 fn set_foo_works() {
 	new_test_ext().execute_with(|| {
-		assert_eq!(Foo::<Test>::get(), Some(24)); // From genesis config.
+		// From genesis config.
+		assert_eq!(Foo::<Test>::get(), Some(FooInfo { current: 24, previous: None }));
 
 		let val1 = 42;
 		assert_ok!(Kitchensink::set_foo(RuntimeOrigin::root(), val1, 2));
-		assert_eq!(Foo::<Test>::get(), Some(val1));
+		assert_eq!(Foo::<Test>::get(), Some(FooInfo { current: val1, previous: Some(24) }));
+	});
+}
+
+#[test]
+fn migration_to_v2_works() {
+	new_test_ext().execute_with(|| {
+		// Pretend we are still on storage version 1, with `Foo` holding a plain `u32`.
+		StorageVersion::new(1).put::<Kitchensink>();
+		frame_support::storage::unhashed::put(&Foo::<Test>::hashed_key(), &7u32);
+
+		<Kitchensink as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		assert_eq!(Kitchensink::on_chain_storage_version(), 2);
+		assert_eq!(Foo::<Test>::get(), Some(FooInfo { current: 7, previous: None }));
+
+		// Running it again is a no-op, since the on-chain version is already 2.
+		assert_ok!(Kitchensink::set_foo(RuntimeOrigin::root(), 42, 2));
+		<Kitchensink as OnRuntimeUpgrade>::on_runtime_upgrade();
+		assert_eq!(Foo::<Test>::get(), Some(FooInfo { current: 42, previous: Some(7) }));
 	});
 }
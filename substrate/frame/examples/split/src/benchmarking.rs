@@ -50,5 +50,15 @@ mod benchmarks {
 		assert_eq!(Something::<T>::get(), Some(101u32));
 	}
 
+	#[benchmark]
+	fn set_something() {
+		let value = 100u32;
+		let caller: T::AccountId = whitelisted_caller();
+		#[extrinsic_call]
+		set_something(RawOrigin::Signed(caller), value);
+
+		assert_eq!(Something::<T>::get(), Some(value));
+	}
+
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
 }
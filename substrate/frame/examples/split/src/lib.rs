@@ -75,6 +75,8 @@ pub mod pallet {
 		NoneValue,
 		/// Errors should have helpful documentation associated with them.
 		StorageOverflow,
+		/// The value to store must not be zero.
+		ZeroValue,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -119,5 +121,22 @@ pub mod pallet {
 				},
 			}
 		}
+
+		/// An example dispatchable that validates its input before writing it to storage, using an
+		/// error declared next to it and an event declared in the `events` section.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_something())]
+		pub fn set_something(origin: OriginFor<T>, value: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Reject invalid input before touching storage.
+			ensure!(value != 0, Error::<T>::ZeroValue);
+
+			<Something<T>>::put(value);
+
+			// The event is declared in the `events` section, imported into this pallet.
+			Self::deposit_event(Event::SomethingStored { something: value, who });
+			Ok(())
+		}
 	}
 }
@@ -42,3 +42,24 @@ fn correct_error_for_none_value() {
 		);
 	});
 }
+
+#[test]
+fn set_something_works() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		System::set_block_number(1);
+		assert_ok!(TemplatePallet::set_something(RuntimeOrigin::signed(1), 7));
+		assert_eq!(Something::<Test>::get(), Some(7));
+		System::assert_last_event(Event::SomethingStored { something: 7, who: 1 }.into());
+	});
+}
+
+#[test]
+fn set_something_rejects_zero() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplatePallet::set_something(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::ZeroValue
+		);
+	});
+}
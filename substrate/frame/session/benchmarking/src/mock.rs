@@ -168,6 +168,7 @@ impl pallet_staking::Config for Test {
 	type Reward = ();
 	type SessionsPerEra = ();
 	type SlashDeferDuration = ();
+	type RewardClaimDelay = ();
 	type AdminOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type BondingDuration = ();
 	type SessionInterface = Self;
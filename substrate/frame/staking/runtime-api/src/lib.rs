@@ -20,8 +20,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
+use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait StakingApi<Balance, AccountId>
 		where
 			Balance: Codec,
@@ -35,5 +37,62 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns true if validator `account` has pages to be claimed for the given era.
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool;
+
+		/// Returns an estimate of the minimum backing stake needed to be elected if the validator
+		/// set size was changed to `new_validator_count`.
+		#[api_version(2)]
+		fn simulate_entry_threshold(new_validator_count: u32) -> Balance;
+
+		/// Returns the reward paid out per reward point in `era`, if known.
+		#[api_version(2)]
+		fn era_reward_per_point(era: sp_staking::EraIndex) -> Option<Balance>;
+
+		/// Returns true if `page` of the rewards of validator `account` for `era` can be claimed
+		/// now.
+		#[api_version(2)]
+		fn is_page_claimable(era: sp_staking::EraIndex, account: AccountId, page: sp_staking::Page) -> bool;
+
+		/// Returns the total reward points of `era` and the reward points of each validator.
+		#[api_version(2)]
+		fn era_reward_points(era: sp_staking::EraIndex) -> (u32, Vec<(AccountId, u32)>);
+
+		/// Returns the era each unlocking chunk of `stash` becomes withdrawable in and its value.
+		#[api_version(2)]
+		fn unlocking_schedule(stash: AccountId) -> Vec<(sp_staking::EraIndex, Balance)>;
+
+		/// Returns an estimate of the reward of `nominator` for `era`, if the era reward is known.
+		#[api_version(2)]
+		fn estimate_pending_reward(nominator: AccountId, era: sp_staking::EraIndex) -> Option<Balance>;
+
+		/// Returns the validators of the active era with their total and own stake.
+		#[api_version(2)]
+		fn active_era_validators() -> Vec<(AccountId, Balance, Balance)>;
+
+		/// Returns the `(era, page)` reward pages of validator `account` that are not claimed yet.
+		#[api_version(2)]
+		fn unclaimed_payout_pages(account: AccountId) -> Vec<(sp_staking::EraIndex, sp_staking::Page)>;
+
+		/// Returns true if `stash` is exposed in the active era, as a validator or a nominator.
+		#[api_version(2)]
+		fn is_exposed_in_active_era(stash: AccountId) -> bool;
+
+		/// Returns the `(validator_payout, remainder)` of an era lasting `era_duration_millis`
+		/// with the given `total_staked` and `total_issuance`.
+		#[api_version(2)]
+		fn projected_era_payout(total_staked: Balance, total_issuance: Balance, era_duration_millis: u64) -> (Balance, Balance);
+
+		/// Returns true if `caller` can currently chill `stash` with `chill_other`.
+		#[api_version(2)]
+		fn can_chill_other(caller: AccountId, stash: AccountId) -> bool;
+
+		/// Returns the `(era, total_stake)` of every era within the history depth that has a
+		/// recorded total stake.
+		#[api_version(2)]
+		fn eras_total_stake_history() -> Vec<(sp_staking::EraIndex, Balance)>;
+
+		/// Returns the share of the payout of `validator` in `era` that goes to `nominator`,
+		/// net of commission, or `None` if `nominator` is not exposed to `validator`.
+		#[api_version(2)]
+		fn nominator_reward_share(era: sp_staking::EraIndex, validator: AccountId, nominator: AccountId) -> Option<sp_runtime::Perbill>;
 	}
 }
@@ -514,6 +514,9 @@ benchmarks! {
 	force_new_era_always {}: _(RawOrigin::Root)
 	verify { assert_eq!(ForceEra::<T>::get(), Forcing::ForceAlways); }
 
+	set_force_era_mode {}: _(RawOrigin::Root, Forcing::ForceNew)
+	verify { assert_eq!(ForceEra::<T>::get(), Forcing::ForceNew); }
+
 	// Worst case scenario, the list of invulnerables is very long.
 	set_invulnerables {
 		let v in 0 .. MaxValidators::<T>::get();
@@ -597,6 +600,49 @@ benchmarks! {
 		assert_eq!(UnappliedSlashes::<T>::get(&era).len(), (MAX_SLASHES - s) as usize);
 	}
 
+	cancel_all_deferred_slashes {
+		let s in 1 .. MAX_SLASHES;
+		let era = EraIndex::one();
+		let dummy = || T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+		let unapplied_slashes = (0 .. s)
+			.map(|_| UnappliedSlash::<T::AccountId, BalanceOf<T>>::default_from(dummy()))
+			.collect::<Vec<_>>();
+		UnappliedSlashes::<T>::insert(era, &unapplied_slashes);
+	}: _(RawOrigin::Root, era, s)
+	verify {
+		assert!(UnappliedSlashes::<T>::get(&era).is_empty());
+	}
+
+	reduce_deferred_slash {
+		let n in 0 .. T::MaxExposurePageSize::get() as u32;
+		let slash_era = EraIndex::one();
+		let era = slash_era + T::SlashDeferDuration::get() + 1;
+		let validator = T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+		let mut unapplied_slashes = (0 .. MAX_SLASHES)
+			.map(|_| UnappliedSlash::<T::AccountId, BalanceOf<T>>::default_from(validator.clone()))
+			.collect::<Vec<_>>();
+		// the slashes of the validator and all nominators are recorded.
+		add_slashing_spans::<T>(&validator, 1);
+		for i in 0 .. n {
+			let nominator = account::<T::AccountId>("nominator", i, SEED);
+			add_slashing_spans::<T>(&nominator, 1);
+			NominatorSlashInEra::<T>::insert(slash_era, &nominator, BalanceOf::<T>::one());
+			unapplied_slashes[0].others.push((nominator, BalanceOf::<T>::one()));
+		}
+		UnappliedSlashes::<T>::insert(era, &unapplied_slashes);
+		ValidatorSlashInEra::<T>::insert(
+			slash_era,
+			&validator,
+			(Perbill::from_percent(10), BalanceOf::<T>::zero()),
+		);
+	}: _(RawOrigin::Root, era, 0, Perbill::from_percent(5), n)
+	verify {
+		assert_eq!(
+			ValidatorSlashInEra::<T>::get(slash_era, &validator).map(|(fraction, _)| fraction),
+			Some(Perbill::from_percent(5))
+		);
+	}
+
 	payout_stakers_alive_staked {
 		let n in 0 .. T::MaxExposurePageSize::get() as u32;
 		let (validator, nominators) = create_validator_with_nominators::<T>(
@@ -682,6 +728,46 @@ benchmarks! {
 		assert!(original_bonded < new_bonded);
 	}
 
+	rebond_all {
+		let l in 1 .. T::MaxUnlockingChunks::get() as u32;
+
+		// clean up any existing state.
+		clear_validators_and_nominators::<T>();
+
+		let origin_weight = MinNominatorBond::<T>::get()
+			.max(T::Currency::minimum_balance())
+			// we use 100 to play friendly with the list threshold values in the mock
+			.max(100u32.into());
+
+		// setup a worst case list scenario.
+		let scenario = ListScenario::<T>::new(origin_weight, true)?;
+		let dest_weight = scenario.dest_weight;
+
+		// spread the amount that will give the user dest_weight across `l` unlocking chunks.
+		let value = (dest_weight - origin_weight) / l.into();
+		assert_ne!(value, Zero::zero());
+		let unlock_chunk = UnlockChunk::<BalanceOf<T>> {
+			value,
+			era: EraIndex::zero(),
+		};
+
+		let controller = scenario.origin_controller1;
+		let mut staking_ledger = Ledger::<T>::get(controller.clone()).unwrap();
+
+		for _ in 0 .. l {
+			staking_ledger.unlocking.try_push(unlock_chunk.clone()).unwrap()
+		}
+		Ledger::<T>::insert(controller.clone(), staking_ledger.clone());
+		let original_bonded: BalanceOf<T> = staking_ledger.active;
+
+		whitelist_account!(controller);
+	}: _(RawOrigin::Signed(controller.clone()))
+	verify {
+		let ledger = Ledger::<T>::get(&controller).ok_or("ledger not created after")?;
+		assert!(ledger.unlocking.is_empty());
+		assert_eq!(ledger.active, original_bonded + value * l.into());
+	}
+
 	reap_stash {
 		let s in 1 .. MAX_SPANS;
 		// clean up any existing state.
@@ -953,6 +1039,14 @@ benchmarks! {
 		assert_eq!(MinCommission::<T>::get(), Perbill::from_percent(100));
 	}
 
+	set_validator_reward_cap {
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let cap: BalanceOf<T> = T::Currency::minimum_balance();
+	}: _(RawOrigin::Root, validator.clone(), Some(cap))
+	verify {
+		assert_eq!(ValidatorRewardCap::<T>::get(&validator), Some(cap));
+	}
+
 	restore_ledger {
 		let (stash, controller) = create_stash_controller::<T>(0, 100, RewardDestination::Staked)?;
 		// corrupt ledger.
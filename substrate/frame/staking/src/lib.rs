@@ -415,7 +415,7 @@ pub enum RewardDestination<AccountId> {
 
 /// Preference of what happens regarding validation.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default, MaxEncodedLen)]
-pub struct ValidatorPrefs {
+pub struct ValidatorPrefs<Balance> {
 	/// Reward that validator takes up-front; only the rest is split between themselves and
 	/// nominators.
 	#[codec(compact)]
@@ -424,6 +424,16 @@ pub struct ValidatorPrefs {
 	/// who is not already nominating this validator may nominate them. By default, validators
 	/// are accepting nominations.
 	pub blocked: bool,
+	/// The backing stake above which this validator is not accepting more nominations, if any.
+	/// Like [`Self::blocked`], this does not affect nominators already nominating this validator.
+	///
+	/// The backing stake is taken from the exposure of the validator in the active era, so it
+	/// lags behind the current nominations: it is the stake of the last election that elected
+	/// the validator, or its own active bond if it was not elected for the active era.
+	pub max_backing: Option<Balance>,
+	/// The active bond below which a nominator may not nominate this validator, if any. Like
+	/// [`Self::blocked`], this does not affect nominators already nominating this validator.
+	pub min_nominator_bond: Option<Balance>,
 }
 
 /// Just a Balance/BlockNumber tuple to encode when a chunk of funds will be unlocked.
@@ -564,6 +574,27 @@ impl<T: Config> StakingLedger<T> {
 		(self, unlocking_balance)
 	}
 
+	/// Re-bond funds that were scheduled for unlocking, starting with the oldest chunks.
+	///
+	/// Returns the updated ledger, and the amount actually rebonded.
+	fn rebond_fifo(mut self, value: BalanceOf<T>) -> (Self, BalanceOf<T>) {
+		let mut unlocking_balance = BalanceOf::<T>::zero();
+
+		for chunk in self.unlocking.iter_mut() {
+			if unlocking_balance >= value {
+				break
+			}
+
+			let diff = value.saturating_sub(unlocking_balance).min(chunk.value);
+			unlocking_balance += diff;
+			self.active += diff;
+			chunk.value -= diff;
+		}
+		self.unlocking.retain(|chunk| !chunk.value.is_zero());
+
+		(self, unlocking_balance)
+	}
+
 	/// Slash the staker for a given amount of balance.
 	///
 	/// This implements a proportional slashing system, whereby we set our preference to slash as
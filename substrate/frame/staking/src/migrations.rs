@@ -60,6 +60,78 @@ impl Default for ObsoleteReleases {
 #[storage_alias]
 type StorageVersion<T: Config> = StorageValue<Pallet<T>, ObsoleteReleases, ValueQuery>;
 
+/// Migrating `ValidatorPrefs` to include the optional `max_backing` and `min_nominator_bond`.
+pub mod v16 {
+	use super::*;
+
+	/// `ValidatorPrefs` as of v15, without `max_backing` and `min_nominator_bond`.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	struct OldValidatorPrefs {
+		#[codec(compact)]
+		commission: Perbill,
+		blocked: bool,
+	}
+
+	impl OldValidatorPrefs {
+		fn upgraded<Balance>(self) -> ValidatorPrefs<Balance> {
+			ValidatorPrefs {
+				commission: self.commission,
+				blocked: self.blocked,
+				max_backing: None,
+				min_nominator_bond: None,
+			}
+		}
+	}
+
+	pub struct VersionUncheckedMigrateV15ToV16<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV15ToV16<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let validators = Validators::<T>::iter_keys().count() as u32;
+			let era_prefs = ErasValidatorPrefs::<T>::iter_keys().count() as u32;
+			Ok((validators, era_prefs).encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated = 0u64;
+			Validators::<T>::translate::<OldValidatorPrefs, _>(|_, old| {
+				translated.saturating_inc();
+				Some(old.upgraded())
+			});
+			ErasValidatorPrefs::<T>::translate::<OldValidatorPrefs, _>(|_, _, old| {
+				translated.saturating_inc();
+				Some(old.upgraded())
+			});
+
+			log!(info, "v16 applied successfully, {} validator prefs migrated.", translated);
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (validators, era_prefs): (u32, u32) =
+				Decode::decode(&mut &state[..]).expect("pre_upgrade encodes the counts; qed");
+			ensure!(
+				Validators::<T>::iter().count() as u32 == validators,
+				"not all validator prefs were migrated"
+			);
+			ensure!(
+				ErasValidatorPrefs::<T>::iter().count() as u32 == era_prefs,
+				"not all era validator prefs were migrated"
+			);
+			Ok(())
+		}
+	}
+
+	pub type MigrateV15ToV16<T> = VersionedMigration<
+		15,
+		16,
+		VersionUncheckedMigrateV15ToV16<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
 /// Migrating `OffendingValidators` from `Vec<(u32, bool)>` to `Vec<u32>`
 pub mod v15 {
 	use super::*;
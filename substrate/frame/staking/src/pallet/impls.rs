@@ -36,7 +36,7 @@ use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_session::historical;
 use sp_runtime::{
 	traits::{
-		Bounded, CheckedAdd, CheckedSub, Convert, One, SaturatedConversion, Saturating,
+		Bounded, CheckedAdd, CheckedDiv, CheckedSub, Convert, One, SaturatedConversion, Saturating,
 		StaticLookup, Zero,
 	},
 	ArithmeticError, Perbill, Percent,
@@ -54,7 +54,8 @@ use crate::{
 	election_size_tracker::StaticTracker, log, slashing, weights::WeightInfo, ActiveEraInfo,
 	BalanceOf, EraInfo, EraPayout, Exposure, ExposureOf, Forcing, IndividualExposure,
 	LedgerIntegrityState, MaxNominationsOf, MaxWinnersOf, Nominations, NominationsQuota,
-	PositiveImbalanceOf, RewardDestination, SessionInterface, StakingLedger, ValidatorPrefs,
+	PositiveImbalanceOf, RewardDestination, RewardPoint, SessionInterface, StakingLedger,
+	ValidatorPrefs,
 };
 
 use super::pallet::*;
@@ -187,6 +188,43 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Rebond `value` out of the unlocking chunks of the ledger of `controller`.
+	///
+	/// The most recent chunks are consumed first, unless `oldest_first` is set.
+	pub(super) fn do_rebond(
+		controller: T::AccountId,
+		value: BalanceOf<T>,
+		oldest_first: bool,
+	) -> DispatchResultWithPostInfo {
+		let ledger = Self::ledger(Controller(controller))?;
+		ensure!(!ledger.unlocking.is_empty(), Error::<T>::NoUnlockChunk);
+
+		let initial_unlocking = ledger.unlocking.len() as u32;
+		let (ledger, rebonded_value) =
+			if oldest_first { ledger.rebond_fifo(value) } else { ledger.rebond(value) };
+		// Last check: the new active amount of ledger must be more than ED.
+		ensure!(ledger.active >= T::Currency::minimum_balance(), Error::<T>::InsufficientBond);
+
+		Self::deposit_event(Event::<T>::Bonded {
+			stash: ledger.stash.clone(),
+			amount: rebonded_value,
+		});
+
+		let stash = ledger.stash.clone();
+		let final_unlocking = ledger.unlocking.len();
+
+		// NOTE: ledger must be updated prior to calling `Self::weight_of`.
+		ledger.update()?;
+		if T::VoterList::contains(&stash) {
+			let _ = T::VoterList::on_update(&stash, Self::weight_of(&stash)).defensive();
+		}
+
+		let removed_chunks = 1u32 // for the case where the last iterated chunk is not removed
+			.saturating_add(initial_unlocking)
+			.saturating_sub(final_unlocking as u32);
+		Ok(Some(T::WeightInfo::rebond(removed_chunks)).into())
+	}
+
 	pub(super) fn do_withdraw_unbonded(
 		controller: &T::AccountId,
 		num_slashing_spans: u32,
@@ -265,6 +303,12 @@ impl<T: Config> Pallet<T> {
 				.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
 		);
 
+		ensure!(
+			current_era >= era.saturating_add(T::RewardClaimDelay::get()),
+			Error::<T>::RewardNotYetClaimable
+				.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
 		ensure!(
 			page < EraInfo::<T>::get_page_count(era, &validator_stash),
 			Error::<T>::InvalidPage.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
@@ -330,8 +374,12 @@ impl<T: Config> Pallet<T> {
 		let validator_total_reward_part =
 			Perbill::from_rational(validator_reward_points, total_reward_points);
 
-		// This is how much validator + nominators are entitled to.
+		// This is how much validator + nominators are entitled to, up to their reward cap.
 		let validator_total_payout = validator_total_reward_part * era_payout;
+		let (validator_total_payout, capped_payout) = match ValidatorRewardCap::<T>::get(&stash) {
+			Some(cap) if validator_total_payout > cap => (cap, validator_total_payout - cap),
+			_ => (validator_total_payout, Zero::zero()),
+		};
 
 		let validator_commission = EraInfo::<T>::get_validator_commission(era, &ledger.stash);
 		// total commission validator takes across all nominator pages
@@ -345,12 +393,23 @@ impl<T: Config> Pallet<T> {
 		let page_stake_part = Perbill::from_rational(exposure.page_total(), exposure.total());
 		// validator commission is paid out in fraction across pages proportional to the page stake.
 		let validator_commission_payout = page_stake_part * validator_total_commission_payout;
+		// the part of the capped reward that would have been paid out with this page.
+		let page_capped_payout = page_stake_part * capped_payout;
 
 		Self::deposit_event(Event::<T>::PayoutStarted {
 			era_index: era,
 			validator_stash: stash.clone(),
 		});
 
+		if !page_capped_payout.is_zero() {
+			T::RewardRemainder::on_unbalanced(T::Currency::issue(page_capped_payout));
+			Self::deposit_event(Event::<T>::ValidatorRewardCapped {
+				era,
+				validator: stash.clone(),
+				excess: page_capped_payout,
+			});
+		}
+
 		let mut total_imbalance = PositiveImbalanceOf::<T>::zero();
 		// We can now make total validator payout:
 		if let Some((imbalance, dest)) =
@@ -392,6 +451,69 @@ impl<T: Config> Pallet<T> {
 		Ok(Some(T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count)).into())
 	}
 
+	/// Ensure that `caller` may chill `stash` through [`Call::chill_other`].
+	pub(crate) fn ensure_can_chill_other(
+		caller: &T::AccountId,
+		stash: &T::AccountId,
+	) -> DispatchResult {
+		let ledger = Self::ledger(Stash(stash.clone()))?;
+		let controller = ledger
+			.controller()
+			.defensive_proof(
+				"Ledger's controller field didn't exist. The controller should have been fetched using StakingLedger.",
+			)
+			.ok_or(Error::<T>::NotController)?;
+
+		// In order for one user to chill another user, the following conditions must be met:
+		//
+		// * `controller` belongs to a nominator who has become non-decodable,
+		//
+		// Or
+		//
+		// * A `ChillThreshold` is set which defines how close to the max nominators or
+		//   validators we must reach before users can start chilling one-another.
+		// * A `MaxNominatorCount` and `MaxValidatorCount` which is used to determine how close
+		//   we are to the threshold.
+		// * A `MinNominatorBond` and `MinValidatorBond` which is the final condition checked to
+		//   determine this is a person that should be chilled because they have not met the
+		//   threshold bond required.
+		//
+		// Otherwise, if caller is the same as the controller, this is just like `chill`.
+
+		if Nominators::<T>::contains_key(stash) && Nominators::<T>::get(stash).is_none() {
+			return Ok(())
+		}
+
+		if *caller != controller {
+			let threshold = ChillThreshold::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
+			let min_active_bond = if Nominators::<T>::contains_key(stash) {
+				let max_nominator_count =
+					MaxNominatorsCount::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
+				let current_nominator_count = Nominators::<T>::count();
+				ensure!(
+					threshold * max_nominator_count < current_nominator_count,
+					Error::<T>::CannotChillOther
+				);
+				MinNominatorBond::<T>::get()
+			} else if Validators::<T>::contains_key(stash) {
+				let max_validator_count =
+					MaxValidatorsCount::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
+				let current_validator_count = Validators::<T>::count();
+				ensure!(
+					threshold * max_validator_count < current_validator_count,
+					Error::<T>::CannotChillOther
+				);
+				MinValidatorBond::<T>::get()
+			} else {
+				Zero::zero()
+			};
+
+			ensure!(ledger.active < min_active_bond, Error::<T>::CannotChillOther);
+		}
+
+		Ok(())
+	}
+
 	/// Chill a stash account.
 	pub(crate) fn chill_stash(stash: &T::AccountId) {
 		let chilled_as_validator = Self::do_remove_validator(stash);
@@ -724,6 +846,11 @@ impl<T: Config> Pallet<T> {
 			.expect("elected_stashes.len() always equal to exposures.len(); qed");
 
 		EraInfo::<T>::set_total_stake(new_planned_era, total_stake);
+		Self::deposit_event(Event::NewEraPlanned {
+			era: new_planned_era,
+			validator_count: elected_stashes.len() as u32,
+			total_stake,
+		});
 
 		// Collect the pref of all winners.
 		for stash in &elected_stashes {
@@ -793,6 +920,7 @@ impl<T: Config> Pallet<T> {
 		// removes controller from `Bonded` and staking ledger from `Ledger`, as well as reward
 		// setting of the stash in `Payee`.
 		StakingLedger::<T>::kill(&stash)?;
+		ValidatorRewardCap::<T>::remove(&stash);
 
 		Self::do_remove_validator(&stash);
 		Self::do_remove_nominator(&stash);
@@ -863,6 +991,8 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Helper to set a new `ForceEra` mode.
+	///
+	/// All changes of `ForceEra` should go through here, so that [`Event::ForceEra`] is emitted.
 	pub(crate) fn set_force_era(mode: Forcing) {
 		log!(info, "Setting force era mode {:?}.", mode);
 		ForceEra::<T>::put(mode);
@@ -1102,7 +1232,7 @@ impl<T: Config> Pallet<T> {
 	/// NOTE: you must ALWAYS use this function to add a validator to the system. Any access to
 	/// `Validators` or `VoterList` outside of this function is almost certainly
 	/// wrong.
-	pub fn do_add_validator(who: &T::AccountId, prefs: ValidatorPrefs) {
+	pub fn do_add_validator(who: &T::AccountId, prefs: ValidatorPrefs<BalanceOf<T>>) {
 		if !Validators::<T>::contains_key(who) {
 			// maybe update sorted list.
 			let _ = T::VoterList::on_insert(who.clone(), Self::weight_of(who))
@@ -1190,6 +1320,269 @@ impl<T: Config> Pallet<T> {
 	pub fn api_pending_rewards(era: EraIndex, account: T::AccountId) -> bool {
 		EraInfo::<T>::pending_rewards(era, &account)
 	}
+
+	pub fn api_era_reward_points(
+		era: EraIndex,
+	) -> (RewardPoint, Vec<(T::AccountId, RewardPoint)>) {
+		let points = Self::eras_reward_points(era);
+		(points.total, points.individual.into_iter().collect())
+	}
+
+	/// Estimate the minimum backing stake needed to enter the validator set if its size was
+	/// changed to `new_validator_count`.
+	///
+	/// This is only an estimate. Validators elected in the active era are ranked by their total
+	/// exposure in that era, while the remaining validator candidates are ranked by their own
+	/// active bond, since the nominator stake they would receive from a new election is unknown.
+	/// Returns zero if there are fewer candidates than `new_validator_count`.
+	pub fn simulate_entry_threshold(new_validator_count: u32) -> BalanceOf<T> {
+		if new_validator_count.is_zero() {
+			return Zero::zero()
+		}
+
+		let active_era = ActiveEra::<T>::get().map(|e| e.index).unwrap_or_default();
+		let mut backings = Validators::<T>::iter_keys()
+			.map(|stash| Self::backing_in_era(active_era, &stash))
+			.collect::<Vec<_>>();
+		backings.sort_unstable_by(|a, b| b.cmp(a));
+
+		backings
+			.get(new_validator_count.saturating_sub(1) as usize)
+			.copied()
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// The stake backing `validator` in `era`.
+	///
+	/// This is the total exposure of `validator` if it was elected in `era`, and its own active
+	/// bond otherwise.
+	pub(crate) fn backing_in_era(era: EraIndex, validator: &T::AccountId) -> BalanceOf<T> {
+		ErasStakersOverview::<T>::get(era, validator).map(|o| o.total).unwrap_or_else(|| {
+			Self::ledger(Stash(validator.clone())).map(|l| l.active).unwrap_or_default()
+		})
+	}
+
+	/// The reward paid out per reward point in `era`.
+	///
+	/// Returns `None` if the era has no validator reward or reward points (yet), or if no points
+	/// were earned in the era.
+	pub fn era_reward_per_point(era: EraIndex) -> Option<BalanceOf<T>> {
+		let validator_reward = ErasValidatorReward::<T>::get(era)?;
+		let total_points = ErasRewardPoints::<T>::get(era).total;
+		validator_reward.checked_div(&total_points.into())
+	}
+
+	/// Whether `page` of the rewards of `validator` for `era` can be claimed right now.
+	///
+	/// This is the case if `era` is within the history depth, has ended and is past the
+	/// [`Config::RewardClaimDelay`], the page exists and it has not been claimed yet.
+	pub fn is_page_claimable(validator: &T::AccountId, era: EraIndex, page: Page) -> bool {
+		let Some(current_era) = CurrentEra::<T>::get() else { return false };
+		if era > current_era ||
+			era < current_era.saturating_sub(T::HistoryDepth::get()) ||
+			current_era < era.saturating_add(T::RewardClaimDelay::get())
+		{
+			return false
+		}
+
+		if page >= EraInfo::<T>::get_page_count(era, validator) ||
+			!ErasValidatorReward::<T>::contains_key(era)
+		{
+			return false
+		}
+
+		Self::ledger(Stash(validator.clone())).map_or(false, |ledger| {
+			!EraInfo::<T>::is_rewards_claimed_with_legacy_fallback(era, &ledger, validator, page)
+		})
+	}
+
+	/// The number of [`Nominators`] entries that can no longer be decoded.
+	///
+	/// This happens if `NominationsQuota::MaxNominations` was decreased below the number of
+	/// targets of a nominator. Such nominators can be removed with [`Pallet::chill_other`].
+	///
+	/// Iterates over all nominators, hence should only be used off-chain.
+	pub fn non_decodable_nominators() -> u32 {
+		let keys = Nominators::<T>::iter_keys().count();
+		let decodable = Nominators::<T>::iter().count();
+		keys.saturating_sub(decodable) as u32
+	}
+
+	/// Estimate the reward of `nominator` for backing validators in `era`.
+	///
+	/// Sums the share of `nominator` in the reward of each validator it was exposed to in `era`,
+	/// after the commission and reward cap of the validator. The validator reward of the era
+	/// already accounts for [`MaxStakedRewards`]. Rewards that were already paid out are included.
+	///
+	/// Returns `None` if `era` is out of history or its validator reward is not computed yet.
+	/// Iterates over all exposures of `era`, hence should only be used off-chain.
+	pub fn estimate_pending_reward(
+		nominator: &T::AccountId,
+		era: EraIndex,
+	) -> Option<BalanceOf<T>> {
+		let current_era = CurrentEra::<T>::get()?;
+		if era > current_era || era < current_era.saturating_sub(T::HistoryDepth::get()) {
+			return None
+		}
+		let era_payout = ErasValidatorReward::<T>::get(era)?;
+		let era_reward_points = ErasRewardPoints::<T>::get(era);
+
+		// The stake of `nominator` behind each validator, and the total stake of that validator.
+		let paged = ErasStakersPaged::<T>::iter_prefix((era,)).filter_map(
+			|((validator, _), exposure_page)| {
+				let value = exposure_page.others.iter().find(|e| &e.who == nominator)?.value;
+				let total = ErasStakersOverview::<T>::get(era, &validator)?.total;
+				Some((validator, value, total))
+			},
+		);
+		// Exposures from before paged exposures were introduced.
+		let clipped =
+			ErasStakersClipped::<T>::iter_prefix(era).filter_map(|(validator, exposure)| {
+				if ErasStakersOverview::<T>::contains_key(era, &validator) {
+					return None
+				}
+				let value = exposure.others.iter().find(|e| &e.who == nominator)?.value;
+				Some((validator, value, exposure.total))
+			});
+
+		let mut reward = BalanceOf::<T>::zero();
+		for (validator, value, total) in paged.chain(clipped) {
+			let validator_reward_points =
+				era_reward_points.individual.get(&validator).copied().unwrap_or_else(Zero::zero);
+			if validator_reward_points.is_zero() {
+				continue
+			}
+
+			let validator_total_payout =
+				Perbill::from_rational(validator_reward_points, era_reward_points.total) *
+					era_payout;
+			let validator_total_payout = ValidatorRewardCap::<T>::get(&validator)
+				.map_or(validator_total_payout, |cap| validator_total_payout.min(cap));
+			let validator_commission = EraInfo::<T>::get_validator_commission(era, &validator);
+			let validator_leftover_payout = validator_total_payout
+				.saturating_sub(validator_commission * validator_total_payout);
+
+			reward = reward
+				.saturating_add(Perbill::from_rational(value, total) * validator_leftover_payout);
+		}
+
+		Some(reward)
+	}
+
+	/// The unlocking chunks of `stash`, as the era each becomes withdrawable in and its value.
+	///
+	/// Chunks with an era not after the current era can already be withdrawn. Returns an empty
+	/// schedule if `stash` is not bonded.
+	pub fn unlocking_schedule(stash: &T::AccountId) -> Vec<(EraIndex, BalanceOf<T>)> {
+		Self::ledger(Stash(stash.clone()))
+			.map(|ledger| ledger.unlocking.iter().map(|chunk| (chunk.era, chunk.value)).collect())
+			.unwrap_or_default()
+	}
+
+	/// The validators of the active era, as their stash, total stake and own stake.
+	///
+	/// Returns an empty list if there is no active era.
+	pub fn active_era_validators() -> Vec<(T::AccountId, BalanceOf<T>, BalanceOf<T>)> {
+		let Some(active_era) = Self::active_era() else { return Vec::new() };
+		ErasStakersOverview::<T>::iter_prefix(active_era.index)
+			.map(|(validator, overview)| (validator, overview.total, overview.own))
+			.collect()
+	}
+
+	/// The reward pages of `validator` that have not been claimed yet, as `(era, page)`.
+	///
+	/// Covers the eras from `current_era - HistoryDepth` up to and including the current era.
+	/// Note that the rewards of eras that have not ended yet cannot be claimed yet.
+	pub fn unclaimed_payout_pages(validator: &T::AccountId) -> Vec<(EraIndex, Page)> {
+		let Some(current_era) = CurrentEra::<T>::get() else { return Vec::new() };
+		let legacy_claimed_rewards = Self::ledger(Stash(validator.clone()))
+			.map(|ledger| ledger.legacy_claimed_rewards)
+			.unwrap_or_default();
+
+		(current_era.saturating_sub(T::HistoryDepth::get())..=current_era)
+			.filter(|era| {
+				// eras without exposure have nothing to claim.
+				(ErasStakersOverview::<T>::contains_key(era, validator) ||
+					ErasStakers::<T>::contains_key(era, validator)) &&
+					!legacy_claimed_rewards.contains(era)
+			})
+			.flat_map(|era| {
+				let claimed = ClaimedRewards::<T>::get(era, validator);
+				(0..EraInfo::<T>::get_page_count(era, validator))
+					.filter(move |page| !claimed.contains(page))
+					.map(move |page| (era, page))
+			})
+			.collect()
+	}
+
+	/// Whether `stash` backs any validator in the active era, either as the validator itself or
+	/// as one of its nominators, on any exposure page.
+	///
+	/// Such a stash cannot be fast-unstaked. Returns `false` if there is no active era.
+	pub fn is_exposed_in_active_era(stash: &T::AccountId) -> bool {
+		let Some(active_era) = Self::active_era() else { return false };
+		<Self as StakingInterface>::is_exposed_in_era(stash, &active_era.index)
+	}
+
+	/// The `(validator_payout, remainder)` that [`Config::EraPayout`] would pay out for an era
+	/// lasting `era_duration_millis` with the given hypothetical `total_staked` and
+	/// `total_issuance`.
+	///
+	/// Nothing is paid out, this only projects the reward curve.
+	pub fn projected_era_payout(
+		total_staked: BalanceOf<T>,
+		total_issuance: BalanceOf<T>,
+		era_duration_millis: u64,
+	) -> (BalanceOf<T>, BalanceOf<T>) {
+		T::EraPayout::era_payout(total_staked, total_issuance, era_duration_millis)
+	}
+
+	/// Whether `caller` could chill `stash` with [`Call::chill_other`] right now.
+	pub fn can_chill_other(caller: &T::AccountId, stash: &T::AccountId) -> bool {
+		Self::ensure_can_chill_other(caller, stash).is_ok()
+	}
+
+	/// The total stake of each era from `current_era - HistoryDepth` up to and including the
+	/// current era, as `(era, total)`.
+	///
+	/// Eras for which no total stake is recorded are skipped.
+	pub fn eras_total_stake_history() -> Vec<(EraIndex, BalanceOf<T>)> {
+		let Some(current_era) = CurrentEra::<T>::get() else { return Vec::new() };
+
+		(current_era.saturating_sub(T::HistoryDepth::get())..=current_era)
+			.filter_map(|era| ErasTotalStake::<T>::try_get(era).ok().map(|total| (era, total)))
+			.collect()
+	}
+
+	/// The share of the payout of `validator` in `era` that goes to `nominator`, net of the
+	/// commission of `validator`.
+	///
+	/// This is the stake of `nominator` over the total exposure of `validator`, on whichever
+	/// exposure page it is. Returns `None` if `nominator` is not exposed to `validator` in `era`.
+	pub fn nominator_reward_share(
+		era: EraIndex,
+		validator: &T::AccountId,
+		nominator: &T::AccountId,
+	) -> Option<Perbill> {
+		let (value, total) = match ErasStakersOverview::<T>::get(era, validator) {
+			Some(overview) => {
+				let value = (0..overview.page_count).find_map(|page| {
+					let exposure_page = ErasStakersPaged::<T>::get((era, validator, page))?;
+					exposure_page.others.into_iter().find(|e| &e.who == nominator).map(|e| e.value)
+				})?;
+				(value, overview.total)
+			},
+			// Exposures from before paged exposures were introduced.
+			None => {
+				let exposure = ErasStakersClipped::<T>::get(era, validator);
+				let value = exposure.others.iter().find(|e| &e.who == nominator)?.value;
+				(value, exposure.total)
+			},
+		};
+		let commission = EraInfo::<T>::get_validator_commission(era, validator);
+
+		Some(commission.left_from_one() * Perbill::from_rational(value, total))
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -1284,10 +1677,7 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 		let stake = MinValidatorBond::<T>::get() * 100u32.into();
 		<Bonded<T>>::insert(target.clone(), target.clone());
 		<Ledger<T>>::insert(target.clone(), StakingLedger::<T>::new(target.clone(), stake));
-		Self::do_add_validator(
-			&target,
-			ValidatorPrefs { commission: Perbill::zero(), blocked: false },
-		);
+		Self::do_add_validator(&target, ValidatorPrefs::default());
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1316,10 +1706,7 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 				.unwrap_or_else(|| MinNominatorBond::<T>::get() * 100u32.into());
 			<Bonded<T>>::insert(v.clone(), v.clone());
 			<Ledger<T>>::insert(v.clone(), StakingLedger::<T>::new(v.clone(), stake));
-			Self::do_add_validator(
-				&v,
-				ValidatorPrefs { commission: Perbill::zero(), blocked: false },
-			);
+			Self::do_add_validator(&v, ValidatorPrefs::default());
 		});
 
 		voters.into_iter().for_each(|(v, s, t)| {
@@ -1979,6 +2366,7 @@ impl<T: Config> Pallet<T> {
 		Self::check_bonded_consistency()?;
 		Self::check_payees()?;
 		Self::check_nominators()?;
+		Self::check_nominators_decodable()?;
 		Self::check_exposures()?;
 		Self::check_paged_exposures()?;
 		Self::check_count()?;
@@ -2281,6 +2669,18 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Invariants:
+	/// * All nominator entries can be decoded.
+	fn check_nominators_decodable() -> Result<(), TryRuntimeError> {
+		let non_decodable = Self::non_decodable_nominators();
+		if non_decodable > 0 {
+			log!(warn, "{} nominators cannot be decoded and should be chilled.", non_decodable);
+			return Err("non-decodable nominators exist".into())
+		}
+
+		Ok(())
+	}
+
 	fn ensure_is_stash(who: &T::AccountId) -> Result<(), &'static str> {
 		ensure!(Self::bonded(who).is_some(), "Not a stash.");
 		Ok(())
@@ -68,7 +68,7 @@ pub mod pallet {
 	use super::*;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(15);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(16);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -188,6 +188,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type SlashDeferDuration: Get<EraIndex>;
 
+		/// Number of eras that must pass after an era before its rewards can be claimed.
+		///
+		/// Rewards of era `e` can only be paid out once the current era is at least `e + delay`.
+		/// Set to 0 if rewards should be claimable as soon as the era has ended.
+		#[pallet::constant]
+		type RewardClaimDelay: Get<EraIndex>;
+
 		/// The origin which can manage less critical staking parameters that does not require root.
 		///
 		/// Supported actions: (1) cancel deferred slash, (2) set minimum commission.
@@ -327,6 +334,15 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MinCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
+	/// The maximum reward a validator and its nominators can receive together per era, if set.
+	///
+	/// Any reward beyond the cap is paid to [`Config::RewardRemainder`] instead.
+	///
+	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type ValidatorRewardCap<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
 	/// Map from all (unlocked) "controller" accounts to the info regarding the staking.
 	///
 	/// Note: All the reads and mutations to this storage *MUST* be done through the methods exposed
@@ -347,7 +363,7 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn validators)]
 	pub type Validators<T: Config> =
-		CountedStorageMap<_, Twox64Concat, T::AccountId, ValidatorPrefs, ValueQuery>;
+		CountedStorageMap<_, Twox64Concat, T::AccountId, ValidatorPrefs<BalanceOf<T>>, ValueQuery>;
 
 	/// The maximum validator count before we stop allowing new validators to join.
 	///
@@ -542,7 +558,7 @@ pub mod pallet {
 		EraIndex,
 		Twox64Concat,
 		T::AccountId,
-		ValidatorPrefs,
+		ValidatorPrefs<BalanceOf<T>>,
 		ValueQuery,
 	>;
 
@@ -694,7 +710,7 @@ pub mod pallet {
 			ValidatorCount::<T>::put(self.validator_count);
 			MinimumValidatorCount::<T>::put(self.minimum_validator_count);
 			Invulnerables::<T>::put(&self.invulnerables);
-			ForceEra::<T>::put(self.force_era);
+			Pallet::<T>::set_force_era(self.force_era);
 			CanceledSlashPayout::<T>::put(self.canceled_payout);
 			SlashRewardFraction::<T>::put(self.slash_reward_fraction);
 			MinNominatorBond::<T>::put(self.min_nominator_bond);
@@ -790,7 +806,7 @@ pub mod pallet {
 		/// The stakers' rewards are getting paid.
 		PayoutStarted { era_index: EraIndex, validator_stash: T::AccountId },
 		/// A validator has set their preferences.
-		ValidatorPrefsSet { stash: T::AccountId, prefs: ValidatorPrefs },
+		ValidatorPrefsSet { stash: T::AccountId, prefs: ValidatorPrefs<BalanceOf<T>> },
 		/// Voters size limit reached.
 		SnapshotVotersSizeExceeded { size: u32 },
 		/// Targets size limit reached.
@@ -799,6 +815,27 @@ pub mod pallet {
 		ForceEra { mode: Forcing },
 		/// Report of a controller batch deprecation.
 		ControllerBatchDeprecated { failures: u32 },
+		/// The per-era reward cap of a validator and its nominators was set, or removed if `None`.
+		ValidatorRewardCapSet { validator: T::AccountId, cap: Option<BalanceOf<T>> },
+		/// The reward cap of a validator bound during a payout, and the `excess` was paid to the
+		/// reward remainder.
+		ValidatorRewardCapped { era: EraIndex, validator: T::AccountId, excess: BalanceOf<T> },
+		/// The reward destination of a stash was set by the admin origin.
+		PayeeSet { stash: T::AccountId },
+		/// All `count` deferred slashes of `era` were cancelled.
+		DeferredSlashesCancelled { era: EraIndex, count: u32 },
+		/// The exposures of the `validator_count` validators elected for `era` were stored,
+		/// backed by `total_stake` in total. Deposited right after [`Event::StakersElected`].
+		NewEraPlanned { era: EraIndex, validator_count: u32, total_stake: BalanceOf<T> },
+		/// The deferred slash `slash_index` of `era` on `validator` was reduced from
+		/// `old_fraction` to `new_fraction`.
+		DeferredSlashReduced {
+			era: EraIndex,
+			slash_index: u32,
+			validator: T::AccountId,
+			old_fraction: Perbill,
+			new_fraction: Perbill,
+		},
 	}
 
 	#[pallet::error]
@@ -870,6 +907,24 @@ pub mod pallet {
 		NotEnoughFunds,
 		/// Operation not allowed for virtual stakers.
 		VirtualStakerNotAllowed,
+		/// The rewards of the era cannot be claimed yet, see [`Config::RewardClaimDelay`].
+		RewardNotYetClaimable,
+		/// A nomination target's backing stake already exceeds its
+		/// [`ValidatorPrefs::max_backing`]. The backing stake is the exposure of the target in
+		/// the active era, or its own active bond if it is not elected.
+		ValidatorAtCapacity,
+		/// Incorrect number of deferred slashes provided.
+		IncorrectSlashCount,
+		/// The nominator's active bond is below a nomination target's
+		/// [`ValidatorPrefs::min_nominator_bond`].
+		BelowValidatorMinBond,
+		/// The new slash fraction is not below the current one.
+		SlashNotReduced,
+		/// Incorrect number of slashed nominators provided.
+		IncorrectNominatorCount,
+		/// The validator has more than one deferred slash for the era, which cannot be reduced
+		/// one by one.
+		MultipleDeferredSlashes,
 	}
 
 	#[pallet::hooks]
@@ -1153,7 +1208,10 @@ pub mod pallet {
 		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
 		#[pallet::call_index(4)]
 		#[pallet::weight(T::WeightInfo::validate())]
-		pub fn validate(origin: OriginFor<T>, prefs: ValidatorPrefs) -> DispatchResult {
+		pub fn validate(
+			origin: OriginFor<T>,
+			prefs: ValidatorPrefs<BalanceOf<T>>,
+		) -> DispatchResult {
 			let controller = ensure_signed(origin)?;
 
 			let ledger = Self::ledger(Controller(controller))?;
@@ -1227,16 +1285,31 @@ pub mod pallet {
 			);
 
 			let old = Nominators::<T>::get(stash).map_or_else(Vec::new, |x| x.targets.into_inner());
+			let active_era = Self::active_era().map(|e| e.index).unwrap_or_default();
 
 			let targets: BoundedVec<_, _> = targets
 				.into_iter()
 				.map(|t| T::Lookup::lookup(t).map_err(DispatchError::from))
 				.map(|n| {
 					n.and_then(|n| {
-						if old.contains(&n) || !Validators::<T>::get(&n).blocked {
-							Ok(n)
-						} else {
+						if old.contains(&n) {
+							return Ok(n)
+						}
+						let prefs = Validators::<T>::get(&n);
+						if prefs.blocked {
 							Err(Error::<T>::BadTarget.into())
+						} else if prefs
+							.max_backing
+							.map_or(false, |max| Self::backing_in_era(active_era, &n) > max)
+						{
+							Err(Error::<T>::ValidatorAtCapacity.into())
+						} else if prefs
+							.min_nominator_bond
+							.map_or(false, |min| ledger.active < min)
+						{
+							Err(Error::<T>::BelowValidatorMinBond.into())
+						} else {
+							Ok(n)
 						}
 					})
 				})
@@ -1583,32 +1656,7 @@ pub mod pallet {
 			#[pallet::compact] value: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
 			let controller = ensure_signed(origin)?;
-			let ledger = Self::ledger(Controller(controller))?;
-			ensure!(!ledger.unlocking.is_empty(), Error::<T>::NoUnlockChunk);
-
-			let initial_unlocking = ledger.unlocking.len() as u32;
-			let (ledger, rebonded_value) = ledger.rebond(value);
-			// Last check: the new active amount of ledger must be more than ED.
-			ensure!(ledger.active >= T::Currency::minimum_balance(), Error::<T>::InsufficientBond);
-
-			Self::deposit_event(Event::<T>::Bonded {
-				stash: ledger.stash.clone(),
-				amount: rebonded_value,
-			});
-
-			let stash = ledger.stash.clone();
-			let final_unlocking = ledger.unlocking.len();
-
-			// NOTE: ledger must be updated prior to calling `Self::weight_of`.
-			ledger.update()?;
-			if T::VoterList::contains(&stash) {
-				let _ = T::VoterList::on_update(&stash, Self::weight_of(&stash)).defensive();
-			}
-
-			let removed_chunks = 1u32 // for the case where the last iterated chunk is not removed
-				.saturating_add(initial_unlocking)
-				.saturating_sub(final_unlocking as u32);
-			Ok(Some(T::WeightInfo::rebond(removed_chunks)).into())
+			Self::do_rebond(controller, value, false)
 		}
 
 		/// Remove all data structures concerning a staker/stash once it is at a state where it can
@@ -1783,61 +1831,7 @@ pub mod pallet {
 		pub fn chill_other(origin: OriginFor<T>, stash: T::AccountId) -> DispatchResult {
 			// Anyone can call this function.
 			let caller = ensure_signed(origin)?;
-			let ledger = Self::ledger(Stash(stash.clone()))?;
-			let controller = ledger
-				.controller()
-				.defensive_proof(
-					"Ledger's controller field didn't exist. The controller should have been fetched using StakingLedger.",
-				)
-				.ok_or(Error::<T>::NotController)?;
-
-			// In order for one user to chill another user, the following conditions must be met:
-			//
-			// * `controller` belongs to a nominator who has become non-decodable,
-			//
-			// Or
-			//
-			// * A `ChillThreshold` is set which defines how close to the max nominators or
-			//   validators we must reach before users can start chilling one-another.
-			// * A `MaxNominatorCount` and `MaxValidatorCount` which is used to determine how close
-			//   we are to the threshold.
-			// * A `MinNominatorBond` and `MinValidatorBond` which is the final condition checked to
-			//   determine this is a person that should be chilled because they have not met the
-			//   threshold bond required.
-			//
-			// Otherwise, if caller is the same as the controller, this is just like `chill`.
-
-			if Nominators::<T>::contains_key(&stash) && Nominators::<T>::get(&stash).is_none() {
-				Self::chill_stash(&stash);
-				return Ok(())
-			}
-
-			if caller != controller {
-				let threshold = ChillThreshold::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
-				let min_active_bond = if Nominators::<T>::contains_key(&stash) {
-					let max_nominator_count =
-						MaxNominatorsCount::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
-					let current_nominator_count = Nominators::<T>::count();
-					ensure!(
-						threshold * max_nominator_count < current_nominator_count,
-						Error::<T>::CannotChillOther
-					);
-					MinNominatorBond::<T>::get()
-				} else if Validators::<T>::contains_key(&stash) {
-					let max_validator_count =
-						MaxValidatorsCount::<T>::get().ok_or(Error::<T>::CannotChillOther)?;
-					let current_validator_count = Validators::<T>::count();
-					ensure!(
-						threshold * max_validator_count < current_validator_count,
-						Error::<T>::CannotChillOther
-					);
-					MinValidatorBond::<T>::get()
-				} else {
-					Zero::zero()
-				};
-
-				ensure!(ledger.active < min_active_bond, Error::<T>::CannotChillOther);
-			}
+			Self::ensure_can_chill_other(&caller, &stash)?;
 
 			Self::chill_stash(&stash);
 			Ok(())
@@ -2088,6 +2082,292 @@ pub mod pallet {
 			);
 			Ok(())
 		}
+
+		/// Rebond a portion of the stash scheduled to be unlocked, consuming the oldest unlocking
+		/// chunks first.
+		///
+		/// This is the same as [`Pallet::rebond`], except that the funds that would be unlocked
+		/// the soonest are rebonded first.
+		///
+		/// The dispatch origin must be signed by the controller.
+		///
+		/// ## Complexity
+		/// - Time complexity: O(L), where L is unlocking chunks
+		/// - Bounded by `MaxUnlockingChunks`.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::rebond(T::MaxUnlockingChunks::get() as u32))]
+		pub fn rebond_fifo(
+			origin: OriginFor<T>,
+			#[pallet::compact] value: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let controller = ensure_signed(origin)?;
+			Self::do_rebond(controller, value, true)
+		}
+
+		/// Caps the reward that `validator` and its nominators receive together per era at `cap`,
+		/// or removes the cap if `cap` is `None`.
+		///
+		/// The part of the reward exceeding the cap is paid to the reward remainder on payout.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		///
+		/// Emits `ValidatorRewardCapSet`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_validator_reward_cap())]
+		pub fn set_validator_reward_cap(
+			origin: OriginFor<T>,
+			validator: T::AccountId,
+			cap: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ValidatorRewardCap::<T>::set(&validator, cap);
+			Self::deposit_event(Event::<T>::ValidatorRewardCapSet { validator, cap });
+			Ok(())
+		}
+
+		/// (Re-)set the payment target of `stash`, bypassing its controller.
+		///
+		/// Can be used to fix the reward destination of stashes whose controller is keyless or
+		/// has been lost.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		///
+		/// Emits `PayeeSet`.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_payee())]
+		pub fn set_payee_for(
+			origin: OriginFor<T>,
+			stash: T::AccountId,
+			payee: RewardDestination<T::AccountId>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let ledger = Self::ledger(Stash(stash.clone()))?;
+
+			ensure!(
+				(payee != {
+					#[allow(deprecated)]
+					RewardDestination::Controller
+				}),
+				Error::<T>::ControllerDeprecated
+			);
+
+			let _ = ledger
+				.set_payee(payee)
+				.defensive_proof("ledger was retrieved from storage, thus its bonded; qed.")?;
+
+			Self::deposit_event(Event::<T>::PayeeSet { stash });
+			Ok(())
+		}
+
+		/// Pay out multiple pages of the stakers behind a validator for the given era.
+		///
+		/// Same as [`Call::payout_stakers_by_page`], but for each of `pages` in order. Stops at
+		/// the first page that is already claimed or does not exist, keeping the pages paid out
+		/// before it. Any other error fails the whole call.
+		///
+		/// The origin of this call must be _Signed_. Any account can call this function, even if
+		/// it is not one of the stakers.
+		#[pallet::call_index(34)]
+		#[pallet::weight(
+			T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get())
+				.saturating_mul(pages.len() as u64)
+		)]
+		pub fn payout_stakers_multi(
+			origin: OriginFor<T>,
+			validator_stash: T::AccountId,
+			era: EraIndex,
+			pages: BoundedVec<Page, T::MaxExposurePageSize>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let page_weight =
+				T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get());
+			let mut consumed = Weight::zero();
+			for page in pages {
+				let result = frame_support::storage::with_storage_layer(|| {
+					Self::do_payout_stakers_by_page(validator_stash.clone(), era, page)
+				});
+				let post_info = result.as_ref().map_or_else(|e| e.post_info, |info| *info);
+				consumed.saturating_accrue(post_info.actual_weight.unwrap_or(page_weight));
+				if let Err(mut e) = result {
+					// the page cannot be paid out, but the pages before it are kept.
+					if e.error == Error::<T>::AlreadyClaimed.into() ||
+						e.error == Error::<T>::InvalidPage.into()
+					{
+						break
+					}
+					e.post_info.actual_weight = Some(consumed);
+					return Err(e)
+				}
+			}
+
+			Ok(Some(consumed).into())
+		}
+
+		/// Cancel enactment of all deferred slashes of an era.
+		///
+		/// Can be called by the `T::AdminOrigin`.
+		///
+		/// Parameters: era of the slashes to kill and `slash_count`, the number of slashes
+		/// deferred for that era or an upper bound of it, which is used for the weight.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::cancel_all_deferred_slashes(*slash_count))]
+		pub fn cancel_all_deferred_slashes(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			slash_count: u32,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let count = UnappliedSlashes::<T>::decode_len(&era).unwrap_or_default() as u32;
+			ensure!(count <= slash_count, Error::<T>::IncorrectSlashCount);
+
+			UnappliedSlashes::<T>::remove(&era);
+
+			Self::deposit_event(Event::<T>::DeferredSlashesCancelled { era, count });
+			Ok(Some(T::WeightInfo::cancel_all_deferred_slashes(count)).into())
+		}
+
+		/// Rebond all funds of the stash scheduled to be unlocked.
+		///
+		/// This is the same as [`Pallet::rebond`] with the total value of all unlocking chunks.
+		///
+		/// The dispatch origin must be signed by the controller.
+		///
+		/// ## Complexity
+		/// - Time complexity: O(L), where L is unlocking chunks
+		/// - Bounded by `MaxUnlockingChunks`.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::rebond_all(T::MaxUnlockingChunks::get() as u32))]
+		pub fn rebond_all(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let controller = ensure_signed(origin)?;
+			let ledger = Self::ledger(Controller(controller.clone()))?;
+			let chunks = ledger.unlocking.len() as u32;
+			let value = ledger
+				.unlocking
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |total, chunk| total.saturating_add(chunk.value));
+
+			Self::do_rebond(controller, value, false)?;
+			Ok(Some(T::WeightInfo::rebond_all(chunks)).into())
+		}
+
+		/// Reduce a deferred slash without cancelling it.
+		///
+		/// Can be called by the `T::AdminOrigin`.
+		///
+		/// Parameters: era and index of the slash for that era to reduce, the new slash fraction,
+		/// which must be below the current slash fraction of the validator for the era of the
+		/// offence, and `nominator_count`, the number of nominators slashed by the slash or an
+		/// upper bound of it, which is used for the weight. The slashed amounts are scaled down
+		/// by the ratio of the new fraction to the current one, and so are the slashes recorded
+		/// for the era and slashing spans of the offence, against which later slashes are
+		/// computed.
+		///
+		/// The slash must be the only deferred slash of the validator for the era. Later slashes
+		/// of the same era only cover the part of the slash fraction beyond the earlier ones, so
+		/// they cannot be reduced on their own.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::reduce_deferred_slash(*nominator_count))]
+		pub fn reduce_deferred_slash(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			slash_index: u32,
+			new_fraction: Perbill,
+			nominator_count: u32,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut unapplied = UnappliedSlashes::<T>::get(&era);
+			let validator = unapplied
+				.get(slash_index as usize)
+				.map(|slash| slash.validator.clone())
+				.ok_or(Error::<T>::InvalidSlashIndex)?;
+			ensure!(
+				unapplied.iter().filter(|slash| slash.validator == validator).count() == 1,
+				Error::<T>::MultipleDeferredSlashes
+			);
+
+			let slash = &mut unapplied[slash_index as usize];
+			let nominators = slash.others.len() as u32;
+			ensure!(nominators <= nominator_count, Error::<T>::IncorrectNominatorCount);
+
+			// slashes of `slash_era` are deferred to `slash_era + SlashDeferDuration + 1`.
+			let slash_era = era.saturating_sub(T::SlashDeferDuration::get()).saturating_sub(1);
+			let (old_fraction, old_value) = ValidatorSlashInEra::<T>::get(slash_era, &validator)
+				.ok_or(Error::<T>::InvalidSlashIndex)?;
+			ensure!(new_fraction < old_fraction, Error::<T>::SlashNotReduced);
+
+			let ratio =
+				Perbill::from_rational(new_fraction.deconstruct(), old_fraction.deconstruct());
+			slashing::reduce_unapplied_slash::<T>(slash, slash_era, ratio);
+
+			let new_value = ratio * old_value;
+			ValidatorSlashInEra::<T>::insert(slash_era, &validator, (new_fraction, new_value));
+			UnappliedSlashes::<T>::insert(&era, &unapplied);
+
+			Self::deposit_event(Event::<T>::DeferredSlashReduced {
+				era,
+				slash_index,
+				validator,
+				old_fraction,
+				new_fraction,
+			});
+			Ok(Some(T::WeightInfo::reduce_deferred_slash(nominators)).into())
+		}
+
+		/// Set the [`Forcing`] mode of new eras.
+		///
+		/// Can be called by the `T::AdminOrigin`, unlike [`Call::force_no_eras`],
+		/// [`Call::force_new_era`] and [`Call::force_new_era_always`] which require Root.
+		///
+		/// Emits [`Event::ForceEra`].
+		///
+		/// # Warning
+		///
+		/// The election process starts multiple blocks before the end of the era. If a new era
+		/// is forced just before a new era is triggered, the election process may not have
+		/// enough blocks to get a result.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::set_force_era_mode())]
+		pub fn set_force_era_mode(origin: OriginFor<T>, mode: Forcing) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::set_force_era(mode);
+			Ok(())
+		}
+
+		/// Pay out the oldest unclaimed page of the stakers behind a validator.
+		///
+		/// - `validator_stash` is the stash account of the validator.
+		///
+		/// Picks the lowest era within `[current_era - history_depth; current_era]` that still
+		/// has unclaimed pages for `validator_stash`, as listed by
+		/// [`Pallet::unclaimed_payout_pages`], and pays out its next page like
+		/// [`Call::payout_stakers_by_page`] does. These are the rewards that are lost first.
+		///
+		/// The origin of this call must be _Signed_. Any account can call this function, even if
+		/// it is not one of the stakers.
+		///
+		/// Fails with [`Error::AlreadyClaimed`] if there is no unclaimed page, and with
+		/// [`Error::InvalidEraToReward`] if the oldest unclaimed page is of an era without reward,
+		/// e.g. the current era.
+		#[pallet::call_index(39)]
+		#[pallet::weight(
+			T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get())
+				.saturating_add(T::DbWeight::get().reads(4 * (T::HistoryDepth::get() as u64 + 1)))
+		)]
+		pub fn payout_oldest_unclaimed(
+			origin: OriginFor<T>,
+			validator_stash: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let (era, page) = Self::unclaimed_payout_pages(&validator_stash)
+				.first()
+				.copied()
+				.ok_or(Error::<T>::AlreadyClaimed)?;
+			Self::do_payout_stakers_by_page(validator_stash, era, page)
+		}
 	}
 }
 
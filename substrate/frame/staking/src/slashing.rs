@@ -393,6 +393,53 @@ fn slash_nominators<T: Config>(
 	reward_payout
 }
 
+/// Scale the amounts of the deferred `slash` for `slash_era` down by `ratio`, and lower the
+/// slashes recorded for the slashed stashes by the same amounts.
+///
+/// Later slashes in the same era or slashing span only slash the difference to these records,
+/// so they must match what is actually going to be slashed. The recorded slash of the validator
+/// for the era itself is kept in `ValidatorSlashInEra` and must be updated by the caller.
+pub(crate) fn reduce_unapplied_slash<T: Config>(
+	slash: &mut UnappliedSlash<T::AccountId, BalanceOf<T>>,
+	slash_era: EraIndex,
+	ratio: Perbill,
+) {
+	let own = ratio * slash.own;
+	reduce_span_slash::<T>(&slash.validator, slash_era, slash.own.saturating_sub(own));
+	slash.own = own;
+
+	for (nominator, value) in slash.others.iter_mut() {
+		let reduced = ratio * *value;
+		let reduction = value.saturating_sub(reduced);
+		NominatorSlashInEra::<T>::mutate(slash_era, &*nominator, |era_slash| {
+			if let Some(era_slash) = era_slash {
+				*era_slash = era_slash.saturating_sub(reduction);
+			}
+		});
+		reduce_span_slash::<T>(nominator, slash_era, reduction);
+		*value = reduced;
+	}
+
+	slash.payout = ratio * slash.payout;
+}
+
+// lowers the slash recorded for the slashing span of `stash` which contains `slash_era`.
+fn reduce_span_slash<T: Config>(
+	stash: &T::AccountId,
+	slash_era: EraIndex,
+	reduction: BalanceOf<T>,
+) {
+	let Some(spans) = crate::SlashingSpans::<T>::get(stash) else { return };
+	if let Some(span) = spans.iter().find(|span| span.contains_era(slash_era)) {
+		let span_slash_key = (stash.clone(), span.index);
+		if SpanSlash::<T>::contains_key(&span_slash_key) {
+			SpanSlash::<T>::mutate(&span_slash_key, |record| {
+				record.slashed = record.slashed.saturating_sub(reduction);
+			});
+		}
+	}
+}
+
 // helper struct for managing a set of spans we are currently inspecting.
 // writes alterations to disk on drop, but only if a slash has been carried out.
 //
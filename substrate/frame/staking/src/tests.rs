@@ -524,6 +524,96 @@ fn blocking_and_kicking_works() {
 		});
 }
 
+#[test]
+fn max_backing_rejects_new_nominations() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 11 is backed by its own 1000 and 101's nomination in the active era.
+		let backing = ErasStakersOverview::<Test>::get(active_era(), &11).unwrap().total;
+		assert_eq!(Staking::backing_in_era(active_era(), &11), backing);
+
+		// a cap at the current backing still accepts new nominations.
+		assert_ok!(Staking::validate(
+			RuntimeOrigin::signed(11),
+			ValidatorPrefs { max_backing: Some(backing), ..Default::default() }
+		));
+		bond_nominator(201, 500, vec![11]);
+
+		// but a lower one does not.
+		assert_ok!(Staking::validate(
+			RuntimeOrigin::signed(11),
+			ValidatorPrefs { max_backing: Some(backing - 1), ..Default::default() }
+		));
+		bond(301, 500);
+		assert_noop!(
+			Staking::nominate(RuntimeOrigin::signed(301), vec![21, 11]),
+			Error::<Test>::ValidatorAtCapacity
+		);
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(301), vec![21]));
+
+		// existing nominators can keep nominating 11.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(201), vec![11, 21]));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![11]));
+	});
+}
+
+#[test]
+fn min_nominator_bond_rejects_nominators_below_it() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Staking::validate(
+			RuntimeOrigin::signed(11),
+			ValidatorPrefs { min_nominator_bond: Some(600), ..Default::default() }
+		));
+
+		// a nominator bonding less than the minimum of 11 cannot nominate it.
+		bond(301, 599);
+		assert_noop!(
+			Staking::nominate(RuntimeOrigin::signed(301), vec![21, 11]),
+			Error::<Test>::BelowValidatorMinBond
+		);
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(301), vec![21]));
+
+		// existing nominators can keep nominating 11, whatever their bond.
+		assert!(Staking::ledger(101.into()).unwrap().active < 600);
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![11]));
+	});
+}
+
+#[test]
+fn min_nominator_bond_accepts_nominators_above_it() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Staking::validate(
+			RuntimeOrigin::signed(11),
+			ValidatorPrefs { min_nominator_bond: Some(600), ..Default::default() }
+		));
+
+		// bonding exactly the minimum is enough.
+		bond_nominator(301, 600, vec![11]);
+		assert_eq!(Nominators::<Test>::get(301).unwrap().targets.into_inner(), vec![11]);
+
+		bond_nominator(401, 1000, vec![11, 21]);
+		assert_eq!(Nominators::<Test>::get(401).unwrap().targets.into_inner(), vec![11, 21]);
+	});
+}
+
+#[test]
+fn new_era_planned_event_summarizes_exposures() {
+	ExtBuilder::default().build_and_execute(|| {
+		let _ = staking_events_since_last_call();
+		mock::start_active_era(1);
+
+		let total_stake: Balance =
+			ErasStakersOverview::<Test>::iter_prefix_values(1).map(|o| o.total).sum();
+		assert_eq!(ErasTotalStake::<Test>::get(1), total_stake);
+
+		let events = staking_events_since_last_call();
+		let elected = events.iter().position(|e| *e == Event::StakersElected).unwrap();
+		assert_eq!(
+			events[elected + 1],
+			Event::NewEraPlanned { era: 1, validator_count: 2, total_stake }
+		);
+	});
+}
+
 #[test]
 fn less_than_needed_candidates_works() {
 	ExtBuilder::default()
@@ -987,6 +1077,35 @@ fn forcing_new_era_works() {
 	});
 }
 
+#[test]
+fn set_force_era_mode_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(ForceEra::<Test>::get(), Forcing::NotForcing);
+
+		// only the admin origin can set the mode.
+		assert_noop!(
+			Staking::set_force_era_mode(RuntimeOrigin::signed(2), Forcing::ForceNone),
+			BadOrigin
+		);
+
+		// which is `1` or root in the mock.
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::set_force_era_mode(RuntimeOrigin::signed(1), Forcing::ForceNone));
+		assert_eq!(ForceEra::<Test>::get(), Forcing::ForceNone);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::ForceEra { mode: Forcing::ForceNone }]
+		);
+
+		assert_ok!(Staking::set_force_era_mode(RuntimeOrigin::root(), Forcing::ForceAlways));
+		assert_eq!(ForceEra::<Test>::get(), Forcing::ForceAlways);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::ForceEra { mode: Forcing::ForceAlways }]
+		);
+	})
+}
+
 #[test]
 fn cannot_transfer_staked_balance() {
 	// Tests that a stash account cannot transfer funds
@@ -1164,6 +1283,77 @@ fn reward_destination_works() {
 	});
 }
 
+#[test]
+fn validator_reward_cap_works() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		let total_payout_0 = current_total_payout_for_duration(reward_time_per_era());
+		let cap = total_payout_0 / 2;
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+
+		assert_noop!(
+			Staking::set_validator_reward_cap(RuntimeOrigin::signed(2), 11, Some(cap)),
+			BadOrigin
+		);
+		assert_ok!(Staking::set_validator_reward_cap(RuntimeOrigin::root(), 11, Some(cap)));
+		assert_eq!(ValidatorRewardCap::<Test>::get(11), Some(cap));
+
+		mock::start_active_era(1);
+		let remainder = RewardRemainderUnbalanced::get();
+		let _ = staking_events_since_last_call();
+		mock::make_all_reward_payment(0);
+
+		// 11 is only rewarded up to the cap, the excess goes to the reward remainder.
+		assert_eq!(Balances::free_balance(11), 1000 + cap);
+		assert_eq!(RewardRemainderUnbalanced::get(), remainder + total_payout_0 - cap);
+		assert!(staking_events_since_last_call().contains(&Event::ValidatorRewardCapped {
+			era: 0,
+			validator: 11,
+			excess: total_payout_0 - cap,
+		}));
+
+		// a cap above the reward does not bind.
+		let total_payout_1 = current_total_payout_for_duration(reward_time_per_era());
+		assert_ok!(Staking::set_validator_reward_cap(
+			RuntimeOrigin::root(),
+			11,
+			Some(total_payout_1)
+		));
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+
+		mock::start_active_era(2);
+		let remainder = RewardRemainderUnbalanced::get();
+		mock::make_all_reward_payment(1);
+
+		assert_eq!(Balances::free_balance(11), 1000 + cap + total_payout_1);
+		assert_eq!(RewardRemainderUnbalanced::get(), remainder);
+		assert!(!staking_events_since_last_call()
+			.iter()
+			.any(|e| matches!(e, Event::ValidatorRewardCapped { .. })));
+
+		// the cap can be removed.
+		assert_ok!(Staking::set_validator_reward_cap(RuntimeOrigin::root(), 11, None));
+		assert_eq!(ValidatorRewardCap::<Test>::get(11), None);
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::ValidatorRewardCapSet { validator: 11, cap: None }]
+		);
+	});
+}
+
+#[test]
+fn validator_reward_cap_is_removed_on_reap() {
+	ExtBuilder::default().existential_deposit(10).build_and_execute(|| {
+		assert_ok!(Staking::set_validator_reward_cap(RuntimeOrigin::root(), 11, Some(10)));
+
+		// make 11 reapable by dropping its ledger below the existential deposit.
+		Ledger::<Test>::insert(11, StakingLedger::<Test>::new(11, 5));
+		assert_ok!(Staking::reap_stash(RuntimeOrigin::signed(20), 11, 0));
+
+		assert!(!<Ledger<Test>>::contains_key(&11));
+		assert_eq!(ValidatorRewardCap::<Test>::get(11), None);
+	});
+}
+
 #[test]
 fn validator_payment_prefs_work() {
 	// Test that validator preferences are correctly honored
@@ -1709,6 +1899,110 @@ fn rebond_is_fifo() {
 	})
 }
 
+#[test]
+fn rebond_fifo_consumes_oldest_chunks_first() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		// Give account 11 some large free balance greater than total
+		let _ = Balances::make_free_balance_be(&11, 1000000);
+
+		// Nothing to rebond yet.
+		assert_noop!(
+			Staking::rebond_fifo(RuntimeOrigin::signed(11), 500),
+			Error::<Test>::NoUnlockChunk
+		);
+
+		// Unbond in three different eras.
+		mock::start_active_era(2);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 400));
+		mock::start_active_era(3);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 300));
+		mock::start_active_era(4);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 200));
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap().unlocking,
+			bounded_vec![
+				UnlockChunk { value: 400, era: 2 + 3 },
+				UnlockChunk { value: 300, era: 3 + 3 },
+				UnlockChunk { value: 200, era: 4 + 3 },
+			]
+		);
+
+		// Rebond the whole oldest chunk and part of the next one.
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::rebond_fifo(RuntimeOrigin::signed(11), 500));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Bonded { stash: 11, amount: 500 }]
+		);
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1000,
+				active: 600,
+				unlocking: bounded_vec![
+					UnlockChunk { value: 200, era: 3 + 3 },
+					UnlockChunk { value: 200, era: 4 + 3 },
+				],
+				legacy_claimed_rewards: bounded_vec![],
+			}
+		);
+
+		// Rebonding more than is unlocking only rebonds what is left.
+		assert_ok!(Staking::rebond_fifo(RuntimeOrigin::signed(11), 1000));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Bonded { stash: 11, amount: 400 }]
+		);
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1000,
+				active: 1000,
+				unlocking: Default::default(),
+				legacy_claimed_rewards: bounded_vec![],
+			}
+		);
+	})
+}
+
+#[test]
+fn rebond_all_works() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		// Nothing to rebond yet.
+		assert_noop!(
+			Staking::rebond_all(RuntimeOrigin::signed(11)).map_err(|e| e.error),
+			Error::<Test>::NoUnlockChunk
+		);
+
+		// Unbond in two different eras.
+		mock::start_active_era(2);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 400));
+		mock::start_active_era(3);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 300));
+		assert_eq!(Staking::ledger(11.into()).unwrap().active, 300);
+
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::rebond_all(RuntimeOrigin::signed(11)));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::Bonded { stash: 11, amount: 700 }]
+		);
+		assert_eq!(
+			Staking::ledger(11.into()).unwrap(),
+			StakingLedgerInspect {
+				stash: 11,
+				total: 1000,
+				active: 1000,
+				unlocking: Default::default(),
+				legacy_claimed_rewards: bounded_vec![],
+			}
+		);
+		assert_eq!(<Test as Config>::VoterList::get_score(&11).unwrap(), 1000);
+	})
+}
+
 #[test]
 fn rebond_emits_right_value_in_event() {
 	// When a user calls rebond with more than can be rebonded, things succeed,
@@ -3351,6 +3645,201 @@ fn remove_multi_deferred() {
 	})
 }
 
+#[test]
+fn cancel_all_deferred_slashes_works() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+
+		assert_eq!(Balances::free_balance(11), 1000);
+		assert_eq!(Balances::free_balance(21), 2000);
+
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+		on_offence_now(
+			&[OffenceDetails {
+				offender: (21, Staking::eras_stakers(active_era(), &21)),
+				reporters: vec![],
+			}],
+			&[Perbill::from_percent(10)],
+		);
+		assert_eq!(UnappliedSlashes::<Test>::get(&4).len(), 2);
+
+		assert_noop!(
+			Staking::cancel_all_deferred_slashes(RuntimeOrigin::signed(2), 4, 2),
+			BadOrigin
+		);
+		// the slash count must not be underestimated.
+		assert_noop!(
+			Staking::cancel_all_deferred_slashes(RuntimeOrigin::root(), 4, 1)
+				.map_err(|e| e.error),
+			Error::<Test>::IncorrectSlashCount
+		);
+
+		let _ = staking_events_since_last_call();
+		assert_ok!(Staking::cancel_all_deferred_slashes(RuntimeOrigin::root(), 4, 2));
+		assert!(UnappliedSlashes::<Test>::get(&4).is_empty());
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::DeferredSlashesCancelled { era: 4, count: 2 }]
+		);
+
+		// nothing is slashed once the era is reached.
+		mock::start_active_era(4);
+		assert_eq!(Balances::free_balance(11), 1000);
+		assert_eq!(Balances::free_balance(21), 2000);
+	})
+}
+
+#[test]
+fn reduce_deferred_slash_works() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+
+		assert_eq!(Balances::free_balance(11), 1000);
+		assert_eq!(Balances::free_balance(101), 2000);
+		let exposure = Staking::eras_stakers(active_era(), &11);
+		let nominated_value = exposure.others.iter().find(|o| o.who == 101).unwrap().value;
+		let nominators = exposure.others.len() as u32;
+
+		// deferred to the start of era 4.
+		on_offence_now(
+			&[OffenceDetails { offender: (11, exposure), reporters: vec![] }],
+			&[Perbill::from_percent(10)],
+		);
+		assert_eq!(UnappliedSlashes::<Test>::get(&4).len(), 1);
+
+		let reduce = |origin, slash_index, fraction, nominator_count| {
+			Staking::reduce_deferred_slash(origin, 4, slash_index, fraction, nominator_count)
+		};
+		assert_noop!(reduce(RuntimeOrigin::signed(2), 0, Perbill::zero(), nominators), BadOrigin);
+		assert_noop!(
+			reduce(RuntimeOrigin::root(), 1, Perbill::zero(), nominators),
+			Error::<Test>::InvalidSlashIndex
+		);
+		assert_noop!(
+			reduce(RuntimeOrigin::root(), 0, Perbill::zero(), nominators - 1),
+			Error::<Test>::IncorrectNominatorCount
+		);
+		// the slash cannot be increased, nor kept as is.
+		for fraction in [Perbill::from_percent(20), Perbill::from_percent(10)] {
+			assert_noop!(
+				reduce(RuntimeOrigin::root(), 0, fraction, nominators),
+				Error::<Test>::SlashNotReduced
+			);
+		}
+
+		let _ = staking_events_since_last_call();
+		assert_ok!(reduce(RuntimeOrigin::root(), 0, Perbill::from_percent(5), nominators));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![Event::DeferredSlashReduced {
+				era: 4,
+				slash_index: 0,
+				validator: 11,
+				old_fraction: Perbill::from_percent(10),
+				new_fraction: Perbill::from_percent(5),
+			}]
+		);
+		// the slash can only be reduced further.
+		assert_noop!(
+			reduce(RuntimeOrigin::root(), 0, Perbill::from_percent(5), nominators),
+			Error::<Test>::SlashNotReduced
+		);
+
+		// the reduced slash is applied once the era is reached.
+		mock::start_active_era(4);
+		let initial_slash = Perbill::from_percent(10) * nominated_value;
+		assert_eq!(Balances::free_balance(11), 950);
+		assert_eq!(Balances::free_balance(101), 2000 - Perbill::from_percent(50) * initial_slash);
+	})
+}
+
+#[test]
+fn offence_after_reduced_deferred_slash_slashes_the_difference() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+
+		let exposure = Staking::eras_stakers(active_era(), &11);
+		let nominated_value = exposure.others.iter().find(|o| o.who == 101).unwrap().value;
+		let nominators = exposure.others.len() as u32;
+
+		on_offence_now(
+			&[OffenceDetails { offender: (11, exposure.clone()), reporters: vec![] }],
+			&[Perbill::from_percent(10)],
+		);
+		assert_ok!(Staking::reduce_deferred_slash(
+			RuntimeOrigin::root(),
+			4,
+			0,
+			Perbill::from_percent(5),
+			nominators
+		));
+		let reduced_slash =
+			Perbill::from_percent(50) * (Perbill::from_percent(10) * nominated_value);
+		assert_eq!(NominatorSlashInEra::<Test>::get(1, 101), Some(reduced_slash));
+
+		// a second offence in the same era is computed against the reduced slash.
+		on_offence_now(
+			&[OffenceDetails { offender: (11, exposure), reporters: vec![] }],
+			&[Perbill::from_percent(8)],
+		);
+		let unapplied = UnappliedSlashes::<Test>::get(&4);
+		assert_eq!(unapplied.len(), 2);
+		assert_eq!(unapplied[1].own, 30);
+
+		// in total, the validator and nominator are slashed by the second offence's fraction.
+		mock::start_active_era(4);
+		let difference =
+			Perbill::from_percent(8) * nominated_value - Perbill::from_percent(5) * nominated_value;
+		assert_eq!(Balances::free_balance(11), 920);
+		assert_eq!(Balances::free_balance(101), 2000 - reduced_slash - difference);
+	})
+}
+
+#[test]
+fn reduce_deferred_slash_rejects_multiple_slashes_in_era() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+
+		let exposure = Staking::eras_stakers(active_era(), &11);
+		let nominators = exposure.others.len() as u32;
+
+		// two offences in the same era, the second one only slashes the extra 10%.
+		on_offence_now(
+			&[OffenceDetails { offender: (11, exposure.clone()), reporters: vec![] }],
+			&[Perbill::from_percent(10)],
+		);
+		on_offence_now(
+			&[OffenceDetails { offender: (11, exposure), reporters: vec![] }],
+			&[Perbill::from_percent(20)],
+		);
+		assert_eq!(UnappliedSlashes::<Test>::get(&4).len(), 2);
+
+		// neither slash can be reduced on its own.
+		for slash_index in 0..2 {
+			assert_noop!(
+				Staking::reduce_deferred_slash(
+					RuntimeOrigin::root(),
+					4,
+					slash_index,
+					Perbill::from_percent(15),
+					nominators
+				),
+				Error::<Test>::MultipleDeferredSlashes
+			);
+		}
+
+		// both slashes are applied in full.
+		mock::start_active_era(4);
+		assert_eq!(Balances::free_balance(11), 800);
+	})
+}
+
 #[test]
 fn slash_kicks_validators_not_nominators_and_disables_nominator_for_kicked_validator() {
 	ExtBuilder::default()
@@ -3386,6 +3875,11 @@ fn slash_kicks_validators_not_nominators_and_disables_nominator_for_kicked_valid
 				staking_events_since_last_call(),
 				vec![
 					Event::StakersElected,
+					Event::NewEraPlanned {
+						era: 1,
+						validator_count: Session::validators().len() as u32,
+						total_stake: ErasTotalStake::<Test>::get(1),
+					},
 					Event::EraPaid { era_index: 0, validator_payout: 11075, remainder: 33225 },
 					Event::SlashReported {
 						validator: 11,
@@ -3458,6 +3952,11 @@ fn non_slashable_offence_disables_validator() {
 				staking_events_since_last_call(),
 				vec![
 					Event::StakersElected,
+					Event::NewEraPlanned {
+						era: 1,
+						validator_count: Session::validators().len() as u32,
+						total_stake: ErasTotalStake::<Test>::get(1),
+					},
 					Event::EraPaid { era_index: 0, validator_payout: 11075, remainder: 33225 },
 					Event::SlashReported {
 						validator: 11,
@@ -3520,6 +4019,11 @@ fn slashing_independent_of_disabling_validator() {
 				staking_events_since_last_call(),
 				vec![
 					Event::StakersElected,
+					Event::NewEraPlanned {
+						era: 1,
+						validator_count: Session::validators().len() as u32,
+						total_stake: ErasTotalStake::<Test>::get(1),
+					},
 					Event::EraPaid { era_index: 0, validator_payout: 11075, remainder: 33225 },
 					Event::SlashReported {
 						validator: 11,
@@ -4120,13 +4624,94 @@ fn test_multi_page_payout_stakers_by_page() {
 		));
 		assert_eq!(Staking::claimed_rewards(last_reward_era, &11), vec![0, 1]);
 
-		// Out of order claims works.
-		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 69, 0));
-		assert_eq!(Staking::claimed_rewards(69, &11), vec![0]);
-		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 23, 1));
-		assert_eq!(Staking::claimed_rewards(23, &11), vec![1]);
-		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 42, 0));
-		assert_eq!(Staking::claimed_rewards(42, &11), vec![0]);
+		// Out of order claims works.
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 69, 0));
+		assert_eq!(Staking::claimed_rewards(69, &11), vec![0]);
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 23, 1));
+		assert_eq!(Staking::claimed_rewards(23, &11), vec![1]);
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 42, 0));
+		assert_eq!(Staking::claimed_rewards(42, &11), vec![0]);
+	});
+}
+
+#[test]
+fn payout_stakers_multi_works() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		let balance = 1000;
+		bond_validator(11, balance);
+		for i in 0..100 {
+			bond_nominator(1000 + i, balance + i as Balance, vec![11]);
+		}
+
+		mock::start_active_era(1);
+		Staking::reward_by_ids(vec![(11, 1)]);
+		// Since `MaxExposurePageSize = 64`, there are two pages of validator exposure.
+		assert_eq!(EraInfo::<Test>::get_page_count(1, &11), 2);
+		mock::start_active_era(2);
+
+		let page_weight = <Test as Config>::WeightInfo::payout_stakers_alive_staked;
+
+		// the payout stops at a page that does not exist, keeping the pages paid before it.
+		let pages = bounded_vec![0, 2, 1];
+		let info =
+			Staking::payout_stakers_multi(RuntimeOrigin::signed(1337), 11, 1, pages).unwrap();
+		assert_eq!(Staking::claimed_rewards(1, &11), vec![0]);
+		assert_eq!(info.actual_weight, Some(page_weight(64).saturating_add(page_weight(0))));
+
+		// or at a page that is already claimed.
+		let info =
+			Staking::payout_stakers_multi(RuntimeOrigin::signed(1337), 11, 1, bounded_vec![1, 0])
+				.unwrap();
+		assert_eq!(Staking::claimed_rewards(1, &11), vec![0, 1]);
+		assert_eq!(info.actual_weight, Some(page_weight(36).saturating_add(page_weight(0))));
+
+		// all requested pages are paid out otherwise.
+		Staking::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(3);
+		let _ = staking_events_since_last_call();
+		let info =
+			Staking::payout_stakers_multi(RuntimeOrigin::signed(1337), 11, 2, bounded_vec![0, 1])
+				.unwrap();
+		assert_eq!(Staking::claimed_rewards(2, &11), vec![0, 1]);
+		assert_eq!(
+			staking_events_since_last_call()
+				.iter()
+				.filter(|e| matches!(e, Event::PayoutStarted { era_index: 2, .. }))
+				.count(),
+			2
+		);
+		// the actual weight is the sum of the weights of both pages.
+		assert_eq!(info.actual_weight, Some(page_weight(64).saturating_add(page_weight(36))));
+	});
+}
+
+#[test]
+fn reward_claim_delay_works() {
+	ExtBuilder::default().reward_claim_delay(2).build_and_execute(|| {
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+
+		// rewards of era 1 are not claimable while it is the current era.
+		assert_noop!(
+			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0)
+				.map_err(|e| e.error),
+			Error::<Test>::RewardNotYetClaimable
+		);
+
+		// nor until two eras have passed.
+		mock::start_active_era(2);
+		assert_noop!(
+			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0)
+				.map_err(|e| e.error),
+			Error::<Test>::RewardNotYetClaimable
+		);
+
+		assert!(!Staking::is_page_claimable(&11, 1, 0));
+
+		mock::start_active_era(3);
+		assert!(Staking::is_page_claimable(&11, 1, 0));
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0));
+		assert_eq!(Staking::claimed_rewards(1, &11), vec![0]);
 	});
 }
 
@@ -4550,7 +5135,7 @@ fn test_commission_paid_across_pages() {
 		bond_validator(11, balance);
 		assert_ok!(Staking::validate(
 			RuntimeOrigin::signed(11),
-			ValidatorPrefs { commission: Perbill::from_percent(commission), blocked: false }
+			ValidatorPrefs { commission: Perbill::from_percent(commission), ..Default::default() }
 		));
 		assert_eq!(Validators::<Test>::count(), 1);
 
@@ -5497,8 +6082,11 @@ mod election_data_provider {
 			// election
 			run_to_block(20);
 			assert_eq!(Staking::next_election_prediction(System::block_number()), 45);
-			assert_eq!(staking_events().len(), 1);
-			assert_eq!(*staking_events().last().unwrap(), Event::StakersElected);
+			assert_eq!(staking_events().len(), 2);
+			assert_eq!(
+				*staking_events().get(staking_events().len() - 2).unwrap(),
+				Event::StakersElected
+			);
 
 			for b in 21..45 {
 				run_to_block(b);
@@ -5508,8 +6096,11 @@ mod election_data_provider {
 			// election
 			run_to_block(45);
 			assert_eq!(Staking::next_election_prediction(System::block_number()), 70);
-			assert_eq!(staking_events().len(), 3);
-			assert_eq!(*staking_events().last().unwrap(), Event::StakersElected);
+			assert_eq!(staking_events().len(), 5);
+			assert_eq!(
+				*staking_events().get(staking_events().len() - 2).unwrap(),
+				Event::StakersElected
+			);
 
 			Staking::force_no_eras(RuntimeOrigin::root()).unwrap();
 			assert_eq!(Staking::next_election_prediction(System::block_number()), u64::MAX);
@@ -5531,13 +6122,13 @@ mod election_data_provider {
 			MinimumValidatorCount::<Test>::put(2);
 			run_to_block(55);
 			assert_eq!(Staking::next_election_prediction(System::block_number()), 55 + 25);
-			assert_eq!(staking_events().len(), 10);
+			assert_eq!(staking_events().len(), 13);
 			assert_eq!(
 				*staking_events().last().unwrap(),
 				Event::ForceEra { mode: Forcing::NotForcing }
 			);
 			assert_eq!(
-				*staking_events().get(staking_events().len() - 2).unwrap(),
+				*staking_events().get(staking_events().len() - 3).unwrap(),
 				Event::StakersElected
 			);
 			// The new era has been planned, forcing is changed from `ForceNew` to `NotForcing`.
@@ -5787,6 +6378,98 @@ fn chill_other_works() {
 		})
 }
 
+#[test]
+fn can_chill_other_matches_chill_other() {
+	ExtBuilder::default()
+		.existential_deposit(100)
+		.balance_factor(100)
+		.min_nominator_bond(1_000)
+		.min_validator_bond(1_500)
+		.build_and_execute(|| {
+			let initial_validators = Validators::<Test>::count();
+			let initial_nominators = Nominators::<Test>::count();
+			for i in 0..15 {
+				let a = 4 * i;
+				let b = 4 * i + 2;
+				Balances::make_free_balance_be(&a, 100_000);
+				Balances::make_free_balance_be(&b, 100_000);
+
+				// Nominator
+				assert_ok!(Staking::bond(RuntimeOrigin::signed(a), 1000, RewardDestination::Stash));
+				assert_ok!(Staking::nominate(RuntimeOrigin::signed(a), vec![1]));
+
+				// Validator
+				assert_ok!(Staking::bond(RuntimeOrigin::signed(b), 1500, RewardDestination::Stash));
+				assert_ok!(Staking::validate(RuntimeOrigin::signed(b), ValidatorPrefs::default()));
+			}
+
+			// Without bonds, limits and a threshold nobody can be chilled by someone else, but
+			// a controller can always chill itself.
+			assert!(!Staking::can_chill_other(&1337, &0));
+			assert!(!Staking::can_chill_other(&1337, &2));
+			assert!(Staking::can_chill_other(&0, &0));
+
+			// Raise the minimum bonds and add limits, but no threshold.
+			assert_ok!(Staking::set_staking_configs(
+				RuntimeOrigin::root(),
+				ConfigOp::Set(1_500),
+				ConfigOp::Set(2_000),
+				ConfigOp::Set(10),
+				ConfigOp::Set(10),
+				ConfigOp::Remove,
+				ConfigOp::Remove,
+				ConfigOp::Noop,
+			));
+			assert!(!Staking::can_chill_other(&1337, &0));
+			assert!(!Staking::can_chill_other(&1337, &2));
+
+			// Add a threshold: 75% of 10 leaves room for 7 nominators and validators.
+			assert_ok!(Staking::set_staking_configs(
+				RuntimeOrigin::root(),
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+				ConfigOp::Set(Percent::from_percent(75)),
+				ConfigOp::Noop,
+				ConfigOp::Noop,
+			));
+			assert_eq!(Nominators::<Test>::count(), 15 + initial_nominators);
+			assert_eq!(Validators::<Test>::count(), 15 + initial_validators);
+
+			// The query does not chill anyone.
+			assert!(Staking::can_chill_other(&1337, &0));
+			assert!(Staking::can_chill_other(&1337, &2));
+			assert_eq!(Nominators::<Test>::count(), 15 + initial_nominators);
+			assert_eq!(Validators::<Test>::count(), 15 + initial_validators);
+
+			// Chill nominators down to the boundary, checking each one first.
+			for i in 7..15 {
+				let a = 4 * i;
+				assert!(Staking::can_chill_other(&1337, &a));
+				assert_ok!(Staking::chill_other(RuntimeOrigin::signed(1337), a));
+			}
+
+			// At 8 nominators one more can still be chilled, at 7 no more.
+			assert_eq!(Nominators::<Test>::count(), 8);
+			assert!(Staking::can_chill_other(&1337, &24));
+			assert_ok!(Staking::chill_other(RuntimeOrigin::signed(1337), 24));
+			assert_eq!(Nominators::<Test>::count(), 7);
+			assert!(!Staking::can_chill_other(&1337, &0));
+			assert_noop!(
+				Staking::chill_other(RuntimeOrigin::signed(1337), 0),
+				Error::<Test>::CannotChillOther
+			);
+
+			// Validators are still above the threshold.
+			assert!(Staking::can_chill_other(&1337, &2));
+			assert_ok!(Staking::chill_other(RuntimeOrigin::signed(1337), 2));
+
+			// Unbonded accounts cannot be chilled.
+			assert!(!Staking::can_chill_other(&1337, &1337));
+		})
+}
+
 #[test]
 fn capped_stakers_works() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -5892,7 +6575,7 @@ fn min_commission_works() {
 		// account 11 controls the stash of itself.
 		assert_ok!(Staking::validate(
 			RuntimeOrigin::signed(11),
-			ValidatorPrefs { commission: Perbill::from_percent(5), blocked: false }
+			ValidatorPrefs { commission: Perbill::from_percent(5), ..Default::default() }
 		));
 
 		// event emitted should be correct
@@ -5900,7 +6583,10 @@ fn min_commission_works() {
 			*staking_events().last().unwrap(),
 			Event::ValidatorPrefsSet {
 				stash: 11,
-				prefs: ValidatorPrefs { commission: Perbill::from_percent(5), blocked: false }
+				prefs: ValidatorPrefs {
+					commission: Perbill::from_percent(5),
+					..Default::default()
+				}
 			}
 		);
 
@@ -5919,7 +6605,7 @@ fn min_commission_works() {
 		assert_noop!(
 			Staking::validate(
 				RuntimeOrigin::signed(11),
-				ValidatorPrefs { commission: Perbill::from_percent(5), blocked: false }
+				ValidatorPrefs { commission: Perbill::from_percent(5), ..Default::default() }
 			),
 			Error::<Test>::CommissionTooLow
 		);
@@ -5927,12 +6613,12 @@ fn min_commission_works() {
 		// can only change to higher.
 		assert_ok!(Staking::validate(
 			RuntimeOrigin::signed(11),
-			ValidatorPrefs { commission: Perbill::from_percent(10), blocked: false }
+			ValidatorPrefs { commission: Perbill::from_percent(10), ..Default::default() }
 		));
 
 		assert_ok!(Staking::validate(
 			RuntimeOrigin::signed(11),
-			ValidatorPrefs { commission: Perbill::from_percent(15), blocked: false }
+			ValidatorPrefs { commission: Perbill::from_percent(15), ..Default::default() }
 		));
 	})
 }
@@ -5998,6 +6684,8 @@ fn change_of_absolute_max_nominations() {
 			assert!(Nominators::<Test>::contains_key(71));
 			// but its value cannot be decoded and default is returned.
 			assert!(Nominators::<Test>::get(71).is_none());
+			assert_eq!(Staking::non_decodable_nominators(), 1);
+			assert!(Staking::do_try_state(System::block_number()).is_err());
 
 			assert_eq!(Staking::electing_voters(bounds).unwrap().len(), 3 + 2);
 			assert!(Nominators::<Test>::contains_key(101));
@@ -6016,6 +6704,7 @@ fn change_of_absolute_max_nominations() {
 			assert!(Nominators::<Test>::contains_key(61));
 			assert!(Nominators::<Test>::get(71).is_none());
 			assert!(Nominators::<Test>::get(61).is_some());
+			assert_eq!(Staking::non_decodable_nominators(), 2);
 			assert_eq!(Staking::electing_voters(bounds).unwrap().len(), 3 + 1);
 
 			// now one of them can revive themselves by re-nominating to a proper value.
@@ -6033,6 +6722,7 @@ fn change_of_absolute_max_nominations() {
 			assert_ok!(Staking::chill_other(RuntimeOrigin::signed(71), 101));
 			assert!(!Nominators::<Test>::contains_key(101));
 			assert!(Nominators::<Test>::get(101).is_none());
+			assert_eq!(Staking::non_decodable_nominators(), 0);
 		})
 }
 
@@ -6072,6 +6762,356 @@ fn api_nominations_quota_works() {
 	})
 }
 
+#[test]
+fn simulate_entry_threshold_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 11 and 21 are elected and share the stake of nominator 101.
+		let overview_total =
+			|v: AccountId| ErasStakersOverview::<Test>::get(active_era(), &v).map(|o| o.total).unwrap();
+		assert_eq!(overview_total(11), 1125);
+		assert_eq!(overview_total(21), 1375);
+
+		// with the current set size, the weakest elected validator defines the threshold.
+		assert_eq!(Staking::simulate_entry_threshold(2), 1125);
+		// shrinking the set keeps only the strongest one.
+		assert_eq!(Staking::simulate_entry_threshold(1), 1375);
+		// growing the set admits 31, which is only backed by its own bond.
+		assert_eq!(Staking::simulate_entry_threshold(3), 500);
+		// not enough candidates to fill the set.
+		assert_eq!(Staking::simulate_entry_threshold(4), 0);
+		assert_eq!(Staking::simulate_entry_threshold(0), 0);
+	})
+}
+
+#[test]
+fn era_reward_per_point_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// nothing known about the era yet.
+		assert_eq!(Staking::era_reward_per_point(1), None);
+
+		ErasValidatorReward::<Test>::insert(1, 1000);
+		// no points earned.
+		assert_eq!(Staking::era_reward_per_point(1), None);
+
+		ErasRewardPoints::<Test>::insert(
+			1,
+			EraRewardPoints { individual: vec![(11, 30), (21, 10)].into_iter().collect(), total: 40 },
+		);
+		assert_eq!(Staking::era_reward_per_point(1), Some(25));
+
+		// points without a reward.
+		ErasRewardPoints::<Test>::insert(
+			2,
+			EraRewardPoints { individual: vec![(11, 1)].into_iter().collect(), total: 1 },
+		);
+		assert_eq!(Staking::era_reward_per_point(2), None);
+	})
+}
+
+#[test]
+fn api_era_reward_points_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(Staking::api_era_reward_points(1), (0, vec![]));
+
+		Pallet::<Test>::reward_by_ids(vec![(11, 3), (21, 1)]);
+		Pallet::<Test>::reward_by_ids(vec![(11, 2)]);
+
+		assert_eq!(Staking::api_era_reward_points(0), (6, vec![(11, 5), (21, 1)]));
+	})
+}
+
+#[test]
+fn is_page_claimable_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		Pallet::<Test>::reward_by_ids(vec![(11, 1), (21, 1)]);
+		mock::start_active_era(1);
+
+		// a page of an ended era is claimable.
+		assert!(Staking::is_page_claimable(&11, 0, 0));
+		// the page does not exist.
+		assert_eq!(EraInfo::<Test>::get_page_count(0, &11), 1);
+		assert!(!Staking::is_page_claimable(&11, 0, 1));
+		// not a validator.
+		assert!(!Staking::is_page_claimable(&101, 0, 0));
+		// the era has not ended yet, or is in the future.
+		assert!(!Staking::is_page_claimable(&11, 1, 0));
+		assert!(!Staking::is_page_claimable(&11, 2, 0));
+
+		// the page is already claimed.
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 0, 0));
+		assert!(!Staking::is_page_claimable(&11, 0, 0));
+
+		// the era is out of the history depth.
+		let history_depth = HistoryDepth::get();
+		mock::start_active_era(history_depth);
+		assert!(Staking::is_page_claimable(&21, 0, 0));
+		mock::start_active_era(history_depth + 1);
+		assert!(!Staking::is_page_claimable(&21, 0, 0));
+		assert!(Staking::is_page_claimable(&21, 1, 0));
+	})
+}
+
+#[test]
+fn estimate_pending_reward_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// the reward of the current era is not computed yet.
+		assert_eq!(Staking::estimate_pending_reward(&101, 0), None);
+
+		Pallet::<Test>::reward_by_ids(vec![(11, 2), (21, 1)]);
+		ErasValidatorPrefs::<Test>::insert(
+			0,
+			11,
+			ValidatorPrefs { commission: Perbill::from_percent(10), ..Default::default() },
+		);
+		mock::start_active_era(1);
+
+		// 101 backs both 11 and 21.
+		let estimate = Staking::estimate_pending_reward(&101, 0).unwrap();
+		assert!(estimate > 0);
+		// not a nominator.
+		assert_eq!(Staking::estimate_pending_reward(&1337, 0), Some(0));
+		// the era is in the future.
+		assert_eq!(Staking::estimate_pending_reward(&101, 2), None);
+
+		// the estimate is the same for clipped exposures.
+		let exposure = EraInfo::<Test>::get_full_exposure(0, &11);
+		<ErasStakersPaged<Test>>::remove((0, 11, 0));
+		<ErasStakersOverview<Test>>::remove(0, 11);
+		<ErasStakersClipped<Test>>::insert(0, 11, exposure);
+		assert_eq!(Staking::estimate_pending_reward(&101, 0), Some(estimate));
+
+		// and matches the actual payout.
+		let before = Balances::total_balance(&101);
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 0, 0));
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 21, 0, 0));
+		assert_eq!(Balances::total_balance(&101) - before, estimate);
+	})
+}
+
+#[test]
+fn unlocking_schedule_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(Staking::unlocking_schedule(&11), vec![]);
+		// not bonded.
+		assert_eq!(Staking::unlocking_schedule(&1337), vec![]);
+
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 100));
+		mock::start_active_era(1);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 200));
+
+		// chunks are withdrawable after the bonding duration.
+		assert_eq!(BondingDuration::get(), 3);
+		assert_eq!(Staking::unlocking_schedule(&11), vec![(3, 100), (4, 200)]);
+
+		// withdrawn chunks are no longer scheduled.
+		mock::start_active_era(3);
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(11), 0));
+		assert_eq!(Staking::unlocking_schedule(&11), vec![(4, 200)]);
+	})
+}
+
+#[test]
+fn active_era_validators_works() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		let sorted_validators = || {
+			let mut validators = Staking::active_era_validators();
+			validators.sort();
+			validators
+		};
+		assert_eq!(sorted_validators(), vec![(11, 1000, 1000), (21, 1000, 1000)]);
+
+		// stake changes only show up once the next era is active.
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(21), 500));
+		assert_eq!(sorted_validators(), vec![(11, 1000, 1000), (21, 1000, 1000)]);
+		mock::start_active_era(1);
+		assert_eq!(sorted_validators(), vec![(11, 1000, 1000), (21, 1500, 1500)]);
+
+		// no active era.
+		ActiveEra::<Test>::kill();
+		assert_eq!(Staking::active_era_validators(), vec![]);
+	})
+}
+
+#[test]
+fn unclaimed_payout_pages_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(Staking::unclaimed_payout_pages(&11), vec![(0, 0)]);
+		// not a validator.
+		assert_eq!(Staking::unclaimed_payout_pages(&101), vec![]);
+
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+		assert_eq!(Staking::unclaimed_payout_pages(&11), vec![(0, 0), (1, 0), (2, 0)]);
+
+		// claimed pages are no longer listed.
+		assert_ok!(Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, 0));
+		assert_eq!(Staking::unclaimed_payout_pages(&11), vec![(0, 0), (2, 0)]);
+
+		// eras out of the history depth are not listed either.
+		HistoryDepth::set(1);
+		assert_eq!(Staking::unclaimed_payout_pages(&11), vec![(2, 0)]);
+	})
+}
+
+#[test]
+fn payout_oldest_unclaimed_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let err_weight = <Test as Config>::WeightInfo::payout_stakers_alive_staked(0);
+
+		// the current era cannot be paid out yet.
+		assert_noop!(
+			Staking::payout_oldest_unclaimed(RuntimeOrigin::signed(1337), 11),
+			Error::<Test>::InvalidEraToReward.with_weight(err_weight)
+		);
+
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(1);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+
+		// the oldest era is paid out first.
+		assert_ok!(Staking::payout_oldest_unclaimed(RuntimeOrigin::signed(1337), 11));
+		assert_eq!(ClaimedRewards::<Test>::get(0, 11), vec![0]);
+		assert!(ClaimedRewards::<Test>::get(1, 11).is_empty());
+		assert_eq!(Staking::unclaimed_payout_pages(&11), vec![(1, 0), (2, 0)]);
+
+		assert_ok!(Staking::payout_oldest_unclaimed(RuntimeOrigin::signed(1337), 11));
+		assert_eq!(ClaimedRewards::<Test>::get(1, 11), vec![0]);
+
+		// only the current era is left.
+		assert_noop!(
+			Staking::payout_oldest_unclaimed(RuntimeOrigin::signed(1337), 11),
+			Error::<Test>::InvalidEraToReward.with_weight(err_weight)
+		);
+
+		// nothing to claim for accounts that are not validators.
+		assert_noop!(
+			Staking::payout_oldest_unclaimed(RuntimeOrigin::signed(1337), 101),
+			Error::<Test>::AlreadyClaimed
+		);
+		assert_noop!(Staking::payout_oldest_unclaimed(RuntimeOrigin::none(), 11), BadOrigin);
+	})
+}
+
+#[test]
+fn is_exposed_in_active_era_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// with a single nominator per page, the second nominator of 11 ends up on page 1.
+		MaxExposurePageSize::set(1);
+		bond_nominator(1000, 500, vec![11]);
+		mock::start_active_era(1);
+
+		assert_eq!(EraInfo::<Test>::get_page_count(1, &11), 2);
+		let page_one = EraInfo::<Test>::get_paged_exposure(1, &11, 1).unwrap();
+		let nominator = page_one.others()[0].who;
+		assert!(Staking::is_exposed_in_active_era(&nominator));
+		// validators are exposed too.
+		assert!(Staking::is_exposed_in_active_era(&11));
+		assert!(Staking::is_exposed_in_active_era(&21));
+
+		// nominations made after the election are not exposed yet.
+		bond_nominator(2000, 500, vec![11]);
+		assert!(!Staking::is_exposed_in_active_era(&2000));
+		// and neither are unrelated accounts.
+		assert!(!Staking::is_exposed_in_active_era(&1337));
+
+		// no active era.
+		ActiveEra::<Test>::kill();
+		assert!(!Staking::is_exposed_in_active_era(&11));
+	})
+}
+
+#[test]
+fn projected_era_payout_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let duration = reward_time_per_era();
+		let (payout, _) = Staking::projected_era_payout(
+			Staking::eras_total_stake(active_era()),
+			Balances::total_issuance(),
+			duration,
+		);
+		assert_eq!(payout, current_total_payout_for_duration(duration));
+
+		// the projection follows the reward curve for hypothetical stake ratios.
+		let issuance = 1_000_000;
+		let (low, low_rest) = Staking::projected_era_payout(100_000, issuance, duration);
+		let (ideal, ideal_rest) = Staking::projected_era_payout(500_000, issuance, duration);
+		assert!(low < ideal);
+		// the maximum payout only depends on the issuance.
+		assert_eq!(low + low_rest, ideal + ideal_rest);
+	})
+}
+
+#[test]
+fn eras_total_stake_history_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let total = Staking::eras_total_stake(0);
+		assert!(total > 0);
+		assert_eq!(Staking::eras_total_stake_history(), vec![(0, total)]);
+
+		mock::start_active_era(1);
+		mock::start_active_era(2);
+		assert_eq!(Staking::eras_total_stake_history(), vec![(0, total), (1, total), (2, total)]);
+
+		// eras without a recorded total are skipped.
+		ErasTotalStake::<Test>::remove(1);
+		assert_eq!(Staking::eras_total_stake_history(), vec![(0, total), (2, total)]);
+
+		// eras out of the history depth are not listed either.
+		HistoryDepth::set(1);
+		assert_eq!(Staking::eras_total_stake_history(), vec![(2, total)]);
+
+		// no current era.
+		CurrentEra::<Test>::kill();
+		assert_eq!(Staking::eras_total_stake_history(), vec![]);
+	})
+}
+
+#[test]
+fn nominator_reward_share_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// with a single nominator per page, the two nominators of 11 end up on different pages.
+		MaxExposurePageSize::set(1);
+		bond_nominator(1000, 500, vec![11]);
+		mock::start_active_era(1);
+		let commission = Perbill::from_percent(10);
+		ErasValidatorPrefs::<Test>::insert(1, 11, ValidatorPrefs { commission, ..Default::default() });
+
+		let exposure = EraInfo::<Test>::get_full_exposure(1, &11);
+		assert_eq!(EraInfo::<Test>::get_page_count(1, &11), 2);
+		for page in 0..2 {
+			let page_exposure = EraInfo::<Test>::get_paged_exposure(1, &11, page).unwrap();
+			let nominator = &page_exposure.others()[0];
+			assert_eq!(
+				Staking::nominator_reward_share(1, &11, &nominator.who),
+				Some(
+					commission.left_from_one() *
+						Perbill::from_rational(nominator.value, exposure.total)
+				)
+			);
+		}
+
+		// the validator itself and unrelated accounts are not nominators of 11.
+		assert_eq!(Staking::nominator_reward_share(1, &11, &11), None);
+		assert_eq!(Staking::nominator_reward_share(1, &11, &1337), None);
+		// and nothing is exposed in eras without exposures.
+		assert_eq!(Staking::nominator_reward_share(2, &11, &101), None);
+
+		// the share is the same for clipped exposures.
+		let share = Staking::nominator_reward_share(1, &11, &101);
+		assert!(share.is_some());
+		for page in 0..2 {
+			<ErasStakersPaged<Test>>::remove((1, 11, page));
+		}
+		<ErasStakersOverview<Test>>::remove(1, 11);
+		<ErasStakersClipped<Test>>::insert(1, 11, exposure);
+		assert_eq!(Staking::nominator_reward_share(1, &11, &101), share);
+		assert_eq!(Staking::nominator_reward_share(1, &11, &1337), None);
+	})
+}
+
 mod sorted_list_provider {
 	use super::*;
 	use frame_election_provider_support::SortedListProvider;
@@ -6125,7 +7165,7 @@ mod sorted_list_provider {
 
 #[test]
 fn force_apply_min_commission_works() {
-	let prefs = |c| ValidatorPrefs { commission: Perbill::from_percent(c), blocked: false };
+	let prefs = |c| ValidatorPrefs { commission: Perbill::from_percent(c), ..Default::default() };
 	let validators = || Validators::<Test>::iter().collect::<Vec<_>>();
 	ExtBuilder::default().build_and_execute(|| {
 		assert_ok!(Staking::validate(RuntimeOrigin::signed(31), prefs(10)));
@@ -6529,7 +7569,7 @@ fn set_min_commission_works_with_admin_origin() {
 		assert_noop!(
 			Staking::validate(
 				RuntimeOrigin::signed(11),
-				ValidatorPrefs { commission: Perbill::from_percent(14), blocked: false }
+				ValidatorPrefs { commission: Perbill::from_percent(14), ..Default::default() }
 			),
 			Error::<Test>::CommissionTooLow
 		);
@@ -6537,7 +7577,7 @@ fn set_min_commission_works_with_admin_origin() {
 		// setting commission >= min_commission works
 		assert_ok!(Staking::validate(
 			RuntimeOrigin::signed(11),
-			ValidatorPrefs { commission: Perbill::from_percent(15), blocked: false }
+			ValidatorPrefs { commission: Perbill::from_percent(15), ..Default::default() }
 		));
 	})
 }
@@ -7590,6 +8630,36 @@ mod ledger {
 		})
 	}
 
+	#[test]
+	#[allow(deprecated)]
+	fn set_payee_for_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			Payee::<Test>::insert(11, RewardDestination::Staked);
+
+			assert_noop!(
+				Staking::set_payee_for(RuntimeOrigin::signed(11), 11, RewardDestination::Stash),
+				BadOrigin
+			);
+			assert_noop!(
+				Staking::set_payee_for(RuntimeOrigin::root(), 11, RewardDestination::Controller),
+				Error::<Test>::ControllerDeprecated
+			);
+			assert_noop!(
+				Staking::set_payee_for(RuntimeOrigin::root(), 42, RewardDestination::Stash),
+				Error::<Test>::NotStash
+			);
+
+			let _ = staking_events_since_last_call();
+			assert_ok!(Staking::set_payee_for(
+				RuntimeOrigin::root(),
+				11,
+				RewardDestination::Account(42)
+			));
+			assert_eq!(Payee::<Test>::get(&11), Some(RewardDestination::Account(42)));
+			assert_eq!(staking_events_since_last_call(), vec![Event::PayeeSet { stash: 11 }]);
+		})
+	}
+
 	#[test]
 	#[allow(deprecated)]
 	fn update_payee_migration_works() {
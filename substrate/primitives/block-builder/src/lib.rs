@@ -23,6 +23,7 @@ extern crate alloc;
 
 use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{traits::Block as BlockT, ApplyExtrinsicResult};
+use sp_weights::Weight;
 
 sp_api::decl_runtime_apis! {
 	/// The `BlockBuilder` api trait that provides the required functionality for building a block.
@@ -50,5 +51,24 @@ sp_api::decl_runtime_apis! {
 
 		/// Check that the inherents are valid. The inherent data will vary from chain to chain.
 		fn check_inherents(block: Block, data: InherentData) -> CheckInherentsResult;
+
+		/// Apply the given extrinsics in order.
+		///
+		/// Returns the inclusion outcome of each applied extrinsic, as [`apply_extrinsic`] would.
+		/// Each extrinsic goes through the same checks as with [`apply_extrinsic`], including its
+		/// weight checks. The batch is not atomic: extrinsics applied before a failing one stay
+		/// applied. Applying stops after the first extrinsic that does not fit into the block
+		/// anymore, so fewer results than extrinsics may be returned.
+		///
+		/// [`apply_extrinsic`]: Self::apply_extrinsic
+		#[api_version(7)]
+		fn apply_extrinsics(
+			extrinsics: alloc::vec::Vec<<Block as BlockT>::Extrinsic>,
+		) -> alloc::vec::Vec<ApplyExtrinsicResult>;
+
+		/// Returns the weight of the `Normal` dispatch class that is still available in the
+		/// current block.
+		#[api_version(7)]
+		fn remaining_block_weight() -> Weight;
 	}
 }
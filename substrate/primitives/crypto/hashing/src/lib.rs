@@ -122,6 +122,11 @@ pub fn sha2_256(data: &[u8]) -> [u8; 32] {
 	sha2::Sha256::digest(data).into()
 }
 
+/// Do a Blake3 256-bit hash and return result.
+pub fn blake3_256(data: &[u8]) -> [u8; 32] {
+	blake3::hash(data).into()
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
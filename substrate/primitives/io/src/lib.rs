@@ -200,6 +200,23 @@ pub trait Storage {
 		})
 	}
 
+	/// Get the length of the value of `key` in the storage or `None` if the key can not be
+	/// found.
+	///
+	/// Unlike [`read`], the value itself is not copied into the runtime.
+	fn len(&self, key: &[u8]) -> Option<u32> {
+		self.storage(key).map(|value| value.len() as u32)
+	}
+
+	/// Returns the data for each of the given `keys` in the storage, in the same order as the
+	/// keys, with `None` for every key that can not be found.
+	///
+	/// This is equivalent to calling [`get`] for each key, but only crosses the runtime/host
+	/// boundary once.
+	fn multi_get(&self, keys: &[Vec<u8>]) -> Vec<Option<Vec<u8>>> {
+		keys.iter().map(|key| self.storage(key)).collect()
+	}
+
 	/// Set `key` to `value` in the storage.
 	fn set(&mut self, key: &[u8], value: &[u8]) {
 		self.set_storage(key.to_vec(), value.to_vec());
@@ -716,6 +733,17 @@ pub trait Misc {
 		log::debug!(target: "runtime", "{}", HexDisplay::from(&data));
 	}
 
+	/// Compare `a` and `b` for equality in constant time.
+	///
+	/// The time taken only depends on the lengths of the inputs, not on their contents, which
+	/// makes it suitable for comparing secrets such as MACs. Inputs of different lengths are
+	/// never equal and `false` is returned.
+	fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+		use subtle::ConstantTimeEq;
+
+		a.ct_eq(b).into()
+	}
+
 	/// Extract the runtime version of the given wasm blob by calling `Core_version`.
 	///
 	/// Returns `None` if calling the function failed for any reason or `Some(Vec<u8>)` where
@@ -876,6 +904,28 @@ pub trait Crypto {
 		res
 	}
 
+	/// Verify a batch of `ed25519` signatures.
+	///
+	/// The signature at each index is verified against the message and public key at the same
+	/// index, with the same backend as [`ed25519_verify`].
+	///
+	/// Returns `true` when all inputs have the same length and all signatures are valid.
+	#[version(2)]
+	fn ed25519_batch_verify(
+		sigs: &[ed25519::Signature],
+		msgs: &[Vec<u8>],
+		pub_keys: &[ed25519::Public],
+	) -> bool {
+		if sigs.len() != msgs.len() || sigs.len() != pub_keys.len() {
+			return false
+		}
+
+		sigs.iter()
+			.zip(msgs)
+			.zip(pub_keys)
+			.all(|((sig, msg), pub_key)| ed25519_verify(sig, msg, pub_key))
+	}
+
 	/// Verify `sr25519` signature.
 	///
 	/// Returns `true` when the verification was successful.
@@ -1284,6 +1334,11 @@ pub trait Hashing {
 		sp_crypto_hashing::blake2_256(data)
 	}
 
+	/// Conduct a 256-bit Blake3 hash.
+	fn blake3_256(data: &[u8]) -> [u8; 32] {
+		sp_crypto_hashing::blake3_256(data)
+	}
+
 	/// Conduct four XX hashes to give a 256-bit result.
 	fn twox_256(data: &[u8]) -> [u8; 32] {
 		sp_crypto_hashing::twox_256(data)
@@ -1878,6 +1933,54 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn storage_len_works() {
+		let value = b"\x0b\0\0\0Hello world".to_vec();
+		let mut t = BasicExternalities::new(Storage {
+			top: map![b":test".to_vec() => value.clone()],
+			children_default: map![],
+		});
+
+		t.execute_with(|| {
+			assert_eq!(storage::len(b":test"), Some(value.len() as u32));
+			assert_eq!(storage::len(b":absent"), None);
+		});
+	}
+
+	#[test]
+	fn ct_eq_works() {
+		use sp_runtime_interface::sp_wasm_interface::HostFunctions as _;
+
+		assert!(misc::ct_eq(b"", b""));
+		assert!(misc::ct_eq(b"hello", b"hello"));
+		assert!(!misc::ct_eq(b"hello", b"hellp"));
+		assert!(!misc::ct_eq(b"hello", b"hello world"));
+		assert!(!misc::ct_eq(b"hello", b""));
+
+		assert!(misc::HostFunctions::host_functions()
+			.iter()
+			.any(|function| function.name() == "ext_misc_ct_eq_version_1"));
+	}
+
+	#[test]
+	fn storage_multi_get_works() {
+		let mut t = BasicExternalities::new(Storage {
+			top: map![
+				b":a".to_vec() => b"hello".to_vec(),
+				b":b".to_vec() => b"world".to_vec()
+			],
+			children_default: map![],
+		});
+
+		t.execute_with(|| {
+			assert_eq!(storage::multi_get(&[]), Vec::<Option<Vec<u8>>>::new());
+			assert_eq!(
+				storage::multi_get(&[b":b".to_vec(), b":absent".to_vec(), b":a".to_vec()]),
+				vec![Some(b"world".to_vec()), None, Some(b"hello".to_vec())],
+			);
+		});
+	}
+
 	#[test]
 	fn clear_prefix_works() {
 		let mut t = BasicExternalities::new(Storage {
@@ -1959,4 +2062,38 @@ mod tests {
 			));
 		});
 	}
+
+	#[test]
+	fn ed25519_batch_verify_works() {
+		let pairs = (0u8..3).map(|i| ed25519::Pair::from_seed(&[i; 32])).collect::<Vec<_>>();
+		let msgs = (0u8..3).map(|i| vec![i; 8]).collect::<Vec<_>>();
+		let mut sigs = pairs.iter().zip(&msgs).map(|(p, m)| p.sign(m)).collect::<Vec<_>>();
+		let pub_keys = pairs.iter().map(|p| p.public()).collect::<Vec<_>>();
+
+		BasicExternalities::default().execute_with(|| {
+			assert!(crypto::ed25519_batch_verify(&sigs, &msgs, &pub_keys));
+
+			// Mismatching lengths.
+			assert!(!crypto::ed25519_batch_verify(&sigs[..2], &msgs, &pub_keys));
+			assert!(!crypto::ed25519_batch_verify(&sigs, &msgs, &pub_keys[..2]));
+
+			// A single invalid signature fails the batch.
+			sigs[1] = pairs[1].sign(&msgs[0]);
+			assert!(!crypto::ed25519_batch_verify(&sigs, &msgs, &pub_keys));
+		});
+	}
+
+	#[test]
+	fn blake3_256_works() {
+		BasicExternalities::default().execute_with(|| {
+			assert_eq!(
+				hashing::blake3_256(b""),
+				[
+					0xaf, 0x13, 0x49, 0xb9, 0xf5, 0xf9, 0xa1, 0xa6, 0xa0, 0x40, 0x4d,
+					0xea, 0x36, 0xdc, 0xc9, 0x49, 0x9b, 0xcb, 0x25, 0xc9, 0xad, 0xc1,
+					0x12, 0xb7, 0xcc, 0x9a, 0x93, 0xca, 0xe4, 0x1f, 0x32, 0x62,
+				]
+			);
+		});
+	}
 }
@@ -66,7 +66,9 @@ use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{
 	create_runtime_str, impl_opaque_keys,
 	traits::{BlakeTwo256, Block as BlockT, DispatchInfoOf, NumberFor, Verify},
-	transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
+	transaction_validity::{
+		InvalidTransaction, TransactionSource, TransactionValidity, TransactionValidityError,
+	},
 	ApplyExtrinsicResult, ExtrinsicInclusionMode, Perbill,
 };
 #[cfg(any(feature = "std", test))]
@@ -517,11 +519,38 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(7)]
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
 		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
 			Executive::apply_extrinsic(extrinsic)
 		}
 
+		fn apply_extrinsics(
+			extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+		) -> Vec<ApplyExtrinsicResult> {
+			let mut results = Vec::with_capacity(extrinsics.len());
+			for extrinsic in extrinsics {
+				let result = Executive::apply_extrinsic(extrinsic);
+				let exhausted = matches!(
+					result,
+					Err(TransactionValidityError::Invalid(InvalidTransaction::ExhaustsResources))
+				);
+				results.push(result);
+				if exhausted {
+					break
+				}
+			}
+			results
+		}
+
+		fn remaining_block_weight() -> Weight {
+			let max = RuntimeBlockWeights::get()
+				.get(DispatchClass::Normal)
+				.max_total
+				.unwrap_or(RuntimeBlockWeights::get().max_block);
+			max.saturating_sub(*System::block_weight().get(DispatchClass::Normal))
+		}
+
 		fn finalize_block() -> <Block as BlockT>::Header {
 			log::trace!(target: LOG_TARGET, "finalize_block");
 			Executive::finalize_block()